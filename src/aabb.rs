@@ -0,0 +1,334 @@
+use mini_math::{Point, Vector3};
+
+use crate::{Distance, LineSegment, Sphere};
+
+/// An axis-aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    /// The corner of the box with the smallest coordinates
+    pub min: Point,
+    /// The corner of the box with the largest coordinates
+    pub max: Point,
+}
+
+impl Aabb {
+    /// Construct a box from its minimum and maximum corners
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Construct the smallest box that contains all of the given points
+    pub fn from_points(points: &[Point]) -> Self {
+        let mut min = Point::from_scalar(f32::INFINITY);
+        let mut max = Point::from_scalar(f32::NEG_INFINITY);
+        for p in points {
+            min = min.min(*p);
+            max = max.max(*p);
+        }
+        Self { min, max }
+    }
+
+    /// The corners of the box, where bit 0 of the index selects the maximum along X, bit 1 along
+    /// Y, and bit 2 along Z
+    pub fn corners(&self) -> [Point; 8] {
+        let corner = |i: usize| {
+            Point::new(
+                if i & 1 == 0 { self.min.x } else { self.max.x },
+                if i & 2 == 0 { self.min.y } else { self.max.y },
+                if i & 4 == 0 { self.min.z } else { self.max.z },
+            )
+        };
+        [0, 1, 2, 3, 4, 5, 6, 7].map(corner)
+    }
+
+    /// The twelve edges of the box
+    pub fn edges(&self) -> [LineSegment; 12] {
+        let c = self.corners();
+        // each edge joins two corners that differ along a single axis
+        [
+            (0, 1),
+            (2, 3),
+            (4, 5),
+            (6, 7),
+            (0, 2),
+            (1, 3),
+            (4, 6),
+            (5, 7),
+            (0, 4),
+            (1, 5),
+            (2, 6),
+            (3, 7),
+        ]
+        .map(|(i, j)| LineSegment::new(c[i], c[j]))
+    }
+
+    /// The center of the box
+    pub fn center(&self) -> Point {
+        self.min + (self.max - self.min) * 0.5
+    }
+
+    /// The distance from the center of the box to each face
+    pub fn half_extents(&self) -> Vector3 {
+        (self.max - self.min) * 0.5
+    }
+
+    /// Whether the given point lies inside the box
+    pub fn contains(&self, p: &Point) -> bool {
+        p.x >= self.min.x
+            && p.y >= self.min.y
+            && p.z >= self.min.z
+            && p.x <= self.max.x
+            && p.y <= self.max.y
+            && p.z <= self.max.z
+    }
+
+    /// The distance from the box to the surface of a sphere, which is negative where they
+    /// overlap, down to minus the radius when the center lies inside the box
+    pub fn signed_distance_to_sphere(&self, sphere: &Sphere) -> f32 {
+        self.distance(&sphere.center) - sphere.radius
+    }
+
+    /// A copy of this box with each face pushed outwards by the given margin.
+    ///
+    /// Negative margins shrink the box, but never past its center.
+    pub fn inflated(&self, margin: f32) -> Aabb {
+        self.inflated_by_vec(Vector3::from_scalar(margin))
+    }
+
+    /// A copy of this box with the faces along each axis pushed outwards by the matching
+    /// component of the given margin.
+    ///
+    /// Negative margins shrink the box, but never past its center.
+    pub fn inflated_by_vec(&self, margin: Vector3) -> Aabb {
+        let center = self.center();
+        let half_extents = (self.half_extents() + margin).max(Vector3::zero());
+        Aabb::new(center - half_extents, center + half_extents)
+    }
+
+    /// The region in which this box overlaps another, if any.
+    ///
+    /// Boxes which only touch produce an overlap with zero thickness.
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        let min = self.min.max(other.min);
+        let max = self.max.min(other.max);
+        if min.x > max.x || min.y > max.y || min.z > max.z {
+            None
+        } else {
+            Some(Aabb::new(min, max))
+        }
+    }
+
+    /// The fraction of the given velocity that this box can move before it touches another,
+    /// stationary, box, along with the normal of the face it hits, pointing back towards this
+    /// box.
+    ///
+    /// The normal lies along the axis on which the boxes come into contact last, preferring X,
+    /// then Y, then Z when the boxes reach an edge or corner exactly. Boxes which merely touch
+    /// on an axis that they don't move along can slide past each other freely, so a box resting
+    /// on the ground can be swept along it. Boxes which already overlap return zero, with the
+    /// normal along the axis of least penetration. Returns `None` if the boxes never touch
+    /// within the full velocity.
+    pub fn sweep_aabb(&self, velocity: Vector3, other: &Aabb) -> Option<(f32, Vector3)> {
+        let axes = [
+            (velocity.x, self.min.x, self.max.x, other.min.x, other.max.x),
+            (velocity.y, self.min.y, self.max.y, other.min.y, other.max.y),
+            (velocity.z, self.min.z, self.max.z, other.min.z, other.max.z),
+        ];
+
+        let mut entry = f32::NEG_INFINITY;
+        let mut exit = f32::INFINITY;
+        let mut entry_axis = None;
+        for (i, &(v, min, max, other_min, other_max)) in axes.iter().enumerate() {
+            let (t0, t1) = if v > 0.0 {
+                ((other_min - max) / v, (other_max - min) / v)
+            } else if v < 0.0 {
+                ((other_max - min) / v, (other_min - max) / v)
+            } else if min < other_max && max > other_min {
+                continue;
+            } else {
+                return None;
+            };
+
+            if t0 > entry {
+                entry = t0;
+                entry_axis = Some(i);
+            }
+            exit = exit.min(t1);
+        }
+
+        if entry >= exit || entry > 1.0 || exit <= 0.0 {
+            return None;
+        }
+
+        let mut normal = Vector3::zero();
+        match entry_axis {
+            Some(i) if entry >= 0.0 => {
+                normal[i] = -axes[i].0.signum();
+                Some((entry, normal))
+            }
+            _ => {
+                // already overlapping, so push out along the shallowest axis
+                let (i, depth) = axes
+                    .iter()
+                    .map(|&(_, min, max, other_min, other_max)| {
+                        let (up, down) = (other_max - min, max - other_min);
+                        if up < down {
+                            up
+                        } else {
+                            -down
+                        }
+                    })
+                    .enumerate()
+                    .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+                normal[i] = depth.signum();
+                Some((0.0, normal))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inflated() {
+        let aabb = Aabb::new(Point::new(-1.0, -2.0, -3.0), Point::new(1.0, 2.0, 3.0));
+
+        let inflated = aabb.inflated(0.5);
+        assert_eq!(inflated.min, Point::new(-1.5, -2.5, -3.5));
+        assert_eq!(inflated.max, Point::new(1.5, 2.5, 3.5));
+
+        // shrinking collapses each axis onto the center rather than inverting
+        let shrunk = aabb.inflated(-1.5);
+        assert_eq!(shrunk.min, Point::new(0.0, -0.5, -1.5));
+        assert_eq!(shrunk.max, Point::new(0.0, 0.5, 1.5));
+    }
+
+    #[test]
+    fn test_inflated_by_vec() {
+        let aabb = Aabb::new(Point::new(-1.0, -2.0, -3.0), Point::new(1.0, 2.0, 3.0));
+
+        let inflated = aabb.inflated_by_vec(Vector3::new(1.0, 0.0, -10.0));
+        assert_eq!(inflated.min, Point::new(-2.0, -2.0, 0.0));
+        assert_eq!(inflated.max, Point::new(2.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let aabb = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 2.0, 2.0));
+
+        // partial overlap
+        let overlap = aabb
+            .intersection(&Aabb::new(
+                Point::new(1.0, 1.0, -1.0),
+                Point::new(3.0, 3.0, 1.0),
+            ))
+            .unwrap();
+        assert_eq!(overlap.min, Point::new(1.0, 1.0, 0.0));
+        assert_eq!(overlap.max, Point::new(2.0, 2.0, 1.0));
+
+        // full containment
+        let inner = Aabb::new(Point::new(0.5, 0.5, 0.5), Point::new(1.0, 1.5, 1.0));
+        let overlap = aabb.intersection(&inner).unwrap();
+        assert_eq!(overlap.min, inner.min);
+        assert_eq!(overlap.max, inner.max);
+
+        // touching faces
+        let overlap = aabb
+            .intersection(&Aabb::new(
+                Point::new(2.0, 0.0, 0.0),
+                Point::new(3.0, 2.0, 2.0),
+            ))
+            .unwrap();
+        assert_eq!(overlap.min, Point::new(2.0, 0.0, 0.0));
+        assert_eq!(overlap.max, Point::new(2.0, 2.0, 2.0));
+
+        // disjoint
+        assert!(aabb
+            .intersection(&Aabb::new(
+                Point::new(3.0, 0.0, 0.0),
+                Point::new(4.0, 2.0, 2.0)
+            ))
+            .is_none());
+    }
+
+    #[test]
+    fn test_corners_and_edges() {
+        let aabb = Aabb::new(Point::new(-1.0, -2.0, -3.0), Point::new(1.0, 2.0, 3.0));
+
+        let corners = aabb.corners();
+        assert_eq!(corners[0], aabb.min);
+        assert_eq!(corners[7], aabb.max);
+        assert_eq!(corners[5], Point::new(1.0, -2.0, 3.0));
+
+        // every edge runs along a single axis, between two distinct corners
+        let edges = aabb.edges();
+        for edge in &edges {
+            let d = edge.end - edge.start;
+            let axes = [d.x, d.y, d.z].iter().filter(|c| **c != 0.0).count();
+            assert_eq!(axes, 1);
+        }
+        let length: f32 = edges.iter().map(|e| (e.end - e.start).magnitude()).sum();
+        assert_eq!(length, 4.0 * (2.0 + 4.0 + 6.0));
+    }
+
+    #[test]
+    fn test_sweep_aabb_sliding() {
+        let ground = Aabb::new(Point::new(-10.0, -1.0, -10.0), Point::new(10.0, 0.0, 10.0));
+        let wall = Aabb::new(Point::new(5.0, 0.0, -10.0), Point::new(6.0, 3.0, 10.0));
+        let player = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 2.0, 1.0));
+        let velocity = Vector3::new(8.0, 0.0, 0.0);
+
+        // resting on the ground doesn't stop the player sliding along it
+        assert_eq!(player.sweep_aabb(velocity, &ground), None);
+
+        let (t, normal) = player.sweep_aabb(velocity, &wall).unwrap();
+        assert!((t - 0.5).abs() < 1e-6);
+        assert_eq!(normal, Vector3::new(-1.0, 0.0, 0.0));
+
+        assert_eq!(player.sweep_aabb(velocity * 0.25, &wall), None);
+        assert_eq!(player.sweep_aabb(-velocity, &wall), None);
+
+        // falling onto the ground
+        let falling = Aabb::new(Point::new(0.0, 1.0, 0.0), Point::new(1.0, 3.0, 1.0));
+        let (t, normal) = falling
+            .sweep_aabb(Vector3::new(0.0, -2.0, 0.0), &ground)
+            .unwrap();
+        assert!((t - 0.5).abs() < 1e-6);
+        assert_eq!(normal, Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_sweep_aabb_corner() {
+        let block = Aabb::new(Point::new(2.0, 2.0, 0.0), Point::new(4.0, 4.0, 1.0));
+        let mover = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, 1.0, 1.0));
+
+        // the X faces meet at t = 0.25, but the Y faces not until t = 0.5
+        let (t, normal) = mover
+            .sweep_aabb(Vector3::new(4.0, 2.0, 0.0), &block)
+            .unwrap();
+        assert!((t - 0.5).abs() < 1e-6);
+        assert_eq!(normal, Vector3::new(0.0, -1.0, 0.0));
+
+        // exactly into the corner prefers the X axis
+        let (t, normal) = mover
+            .sweep_aabb(Vector3::new(2.0, 2.0, 0.0), &block)
+            .unwrap();
+        assert!((t - 0.5).abs() < 1e-6);
+        assert_eq!(normal, Vector3::new(-1.0, 0.0, 0.0));
+
+        // passing just beside the corner misses
+        assert_eq!(mover.sweep_aabb(Vector3::new(4.0, 0.9, 0.0), &block), None);
+    }
+
+    #[test]
+    fn test_sweep_aabb_overlapping() {
+        let a = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 2.0, 2.0));
+        let b = Aabb::new(Point::new(1.5, 0.5, 0.5), Point::new(3.5, 1.5, 1.5));
+
+        let (t, normal) = b.sweep_aabb(Vector3::zero(), &a).unwrap();
+        assert_eq!(t, 0.0);
+        assert_eq!(normal, Vector3::new(1.0, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,224 @@
+use mini_math::Vector3;
+
+use crate::{Aabb, Capsule, Ellipsoid, LineSegment, Sphere, Triangle};
+
+/// Trait for finite shapes which can report their bounds
+pub trait Bounded {
+    /// A sphere which contains the entire shape
+    fn bounding_sphere(&self) -> Sphere;
+    /// An axis-aligned box which contains the entire shape
+    fn bounding_aabb(&self) -> Aabb;
+}
+
+impl Bounded for Sphere {
+    fn bounding_sphere(&self) -> Sphere {
+        *self
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        let r = Vector3::from_scalar(self.radius);
+        Aabb::new(self.center - r, self.center + r)
+    }
+}
+
+impl Bounded for Capsule {
+    fn bounding_sphere(&self) -> Sphere {
+        let axis = self.axis.bounding_sphere();
+        Sphere::new(axis.center, axis.radius + self.radius)
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        let axis = self.axis.bounding_aabb();
+        let r = Vector3::from_scalar(self.radius);
+        Aabb::new(axis.min - r, axis.max + r)
+    }
+}
+
+impl Bounded for Triangle {
+    /// Note that this is centered on the centroid, and isn't the minimal bounding sphere
+    fn bounding_sphere(&self) -> Sphere {
+        let center = self.centroid();
+        let radius = (self.a - center)
+            .magnitude_squared()
+            .max((self.b - center).magnitude_squared())
+            .max((self.c - center).magnitude_squared())
+            .sqrt();
+        Sphere::new(center, radius)
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        Aabb::from_points(&[self.a, self.b, self.c])
+    }
+}
+
+impl Bounded for LineSegment {
+    fn bounding_sphere(&self) -> Sphere {
+        let half = (self.end - self.start) * 0.5;
+        Sphere::new(self.start + half, half.magnitude())
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        Aabb::from_points(&[self.start, self.end])
+    }
+}
+
+impl Bounded for Ellipsoid {
+    fn bounding_sphere(&self) -> Sphere {
+        let radius = self.radii.x.max(self.radii.y).max(self.radii.z);
+        Sphere::new(self.center, radius)
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        Aabb::new(self.center - self.radii, self.center + self.radii)
+    }
+}
+
+impl Bounded for Aabb {
+    fn bounding_sphere(&self) -> Sphere {
+        Sphere::new(self.center(), self.half_extents().magnitude())
+    }
+
+    fn bounding_aabb(&self) -> Aabb {
+        *self
+    }
+}
+
+impl From<&Capsule> for Sphere {
+    /// The bounding sphere of the capsule
+    fn from(capsule: &Capsule) -> Self {
+        capsule.bounding_sphere()
+    }
+}
+
+impl From<&Capsule> for Aabb {
+    /// The bounding box of the capsule
+    fn from(capsule: &Capsule) -> Self {
+        capsule.bounding_aabb()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::Point;
+
+    const TOLERANCE: f32 = 1e-5;
+
+    fn assert_bounds_contain<T: Bounded>(shape: &T, points: &[Point]) {
+        let sphere = shape.bounding_sphere();
+        let aabb = shape.bounding_aabb();
+        let slack = Vector3::from_scalar(TOLERANCE);
+        let aabb = Aabb::new(aabb.min - slack, aabb.max + slack);
+
+        for p in points {
+            assert!((*p - sphere.center).magnitude() <= sphere.radius + TOLERANCE);
+            assert!(aabb.contains(p));
+        }
+    }
+
+    fn directions() -> Vec<Vector3> {
+        let mut result = vec![];
+        for x in [-1.0, 0.0, 1.0] {
+            for y in [-1.0, 0.0, 1.0] {
+                for z in [-1.0, 0.0, 1.0] {
+                    let v = Vector3::new(x, y, z);
+                    if v.magnitude_squared() > 0.0 {
+                        result.push(v.normalized());
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_sphere_bounds() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        let points: Vec<Point> = directions()
+            .into_iter()
+            .map(|d| sphere.center + d * sphere.radius)
+            .collect();
+        assert_bounds_contain(&sphere, &points);
+    }
+
+    #[test]
+    fn test_capsule_bounds() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(3.0, 4.0, 0.0), 1.0);
+        let mut points = vec![];
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let center = capsule.axis.point_at(t);
+            for d in directions() {
+                points.push(center + d * capsule.radius);
+            }
+        }
+        assert_bounds_contain(&capsule, &points);
+    }
+
+    #[test]
+    fn test_triangle_bounds() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(4.0, 2.0, -1.0),
+            Point::new(0.0, -3.0, 1.0),
+        );
+        let mut points = vec![triangle.a, triangle.b, triangle.c];
+        for (u, v) in [(0.2, 0.3), (0.5, 0.5), (0.0, 0.9), (0.33, 0.33)] {
+            points.push(triangle.a + (triangle.b - triangle.a) * u + (triangle.c - triangle.a) * v);
+        }
+        assert_bounds_contain(&triangle, &points);
+    }
+
+    #[test]
+    fn test_line_segment_bounds() {
+        let segment = LineSegment::new(Point::new(-2.0, 1.0, 5.0), Point::new(3.0, -1.0, 0.0));
+        let points: Vec<Point> = [0.0, 0.1, 0.5, 0.9, 1.0]
+            .iter()
+            .map(|t| segment.point_at(*t))
+            .collect();
+        assert_bounds_contain(&segment, &points);
+    }
+
+    #[test]
+    fn test_ellipsoid_bounds() {
+        let ellipsoid = Ellipsoid::new(Point::new(1.0, 0.0, -1.0), Vector3::new(3.0, 1.0, 2.0));
+        let points: Vec<Point> = directions()
+            .into_iter()
+            .map(|d| ellipsoid.center + d * ellipsoid.radii)
+            .collect();
+        assert_bounds_contain(&ellipsoid, &points);
+    }
+
+    #[test]
+    fn test_aabb_bounds() {
+        let aabb = Aabb::new(Point::new(-1.0, -2.0, -3.0), Point::new(1.0, 2.0, 3.0));
+        let mut points = vec![];
+        for x in [aabb.min.x, aabb.max.x] {
+            for y in [aabb.min.y, aabb.max.y] {
+                for z in [aabb.min.z, aabb.max.z] {
+                    points.push(Point::new(x, y, z));
+                }
+            }
+        }
+        assert_bounds_contain(&aabb, &points);
+    }
+
+    #[test]
+    fn test_capsule_conversions() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(3.0, 4.0, 0.0), 1.0);
+
+        let sphere = Sphere::from(&capsule);
+        assert_eq!(sphere, Sphere::new(Point::new(1.5, 2.0, 0.0), 3.5));
+        for cap in capsule.cap_spheres() {
+            assert!((cap.center - sphere.center).magnitude() + cap.radius <= sphere.radius);
+        }
+
+        let aabb = Aabb::from(&capsule);
+        assert_eq!(
+            aabb,
+            Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(4.0, 5.0, 1.0))
+        );
+        for segment in capsule.wireframe(16) {
+            assert!(aabb.contains(&segment.start) && aabb.contains(&segment.end));
+        }
+    }
+}
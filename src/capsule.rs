@@ -1,9 +1,23 @@
-use mini_math::Point;
+use mini_math::{Matrix4, Point, Vector3};
 
-use crate::LineSegment;
+use crate::{
+    check_finite, safe_normalized, scale_of, ClosestPoint, Distance, LineSegment, Ray, ShapeError,
+    Sphere, ANGULAR_EPSILON, LINEAR_EPSILON,
+};
+
+/// A region of the surface of a capsule
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapsuleRegion {
+    /// The hemispherical cap around the start of the axis
+    CapA,
+    /// The cylindrical side between the caps
+    Side,
+    /// The hemispherical cap around the end of the axis
+    CapB,
+}
 
 /// A cylinder capped with a half-sphere at each end
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Capsule {
     /// The central axis of the capsule
     pub axis: LineSegment,
@@ -19,4 +33,510 @@ impl Capsule {
             radius,
         }
     }
+
+    /// Construct a capsule from the end points of the central axis, and a radius, rejecting
+    /// non-finite inputs
+    pub fn try_new(a: Point, b: Point, radius: f32) -> Result<Self, ShapeError> {
+        check_finite(&[a.x, a.y, a.z, b.x, b.y, b.z, radius])?;
+        Ok(Self::new(a, b, radius))
+    }
+
+    /// The distance from the surface of the capsule to a point, which is negative inside
+    pub fn signed_distance(&self, p: &Point) -> f32 {
+        self.axis.distance(p) - self.radius
+    }
+
+    /// The surface area of the capsule
+    pub fn surface_area(&self) -> f32 {
+        let length = (self.axis.end - self.axis.start).magnitude();
+        2.0 * std::f32::consts::PI * self.radius * (length + 2.0 * self.radius)
+    }
+
+    /// The inertia tensor of a solid capsule with the given mass, about its center.
+    ///
+    /// The mass is divided between the cylinder and the two hemispherical caps by volume. As with
+    /// `Sphere::inertia_tensor`, the tensor occupies the upper-left of a `Matrix4`.
+    pub fn inertia_tensor(&self, mass: f32) -> Matrix4 {
+        let axis = self.axis.end - self.axis.start;
+        let h = axis.magnitude();
+        let r = self.radius;
+
+        let cylinder_volume = std::f32::consts::PI * r * r * h;
+        let caps_volume = 4.0 / 3.0 * std::f32::consts::PI * r * r * r;
+        let cylinder_mass = mass * cylinder_volume / (cylinder_volume + caps_volume);
+        let caps_mass = mass - cylinder_mass;
+
+        // moments about the axis, and about any line through the center perpendicular to it
+        let axial = cylinder_mass * r * r / 2.0 + caps_mass * 0.4 * r * r;
+        let perpendicular = cylinder_mass * (h * h / 12.0 + r * r / 4.0)
+            + caps_mass * (0.4 * r * r + h * h / 4.0 + 3.0 * h * r / 8.0);
+
+        // I = perpendicular * identity + (axial - perpendicular) * u * u^T
+        let u = axis.normalized();
+        let u = [u.x, u.y, u.z];
+        let mut columns = [[0.0; 4]; 4];
+        for (i, column) in columns.iter_mut().take(3).enumerate() {
+            for (j, value) in column.iter_mut().take(3).enumerate() {
+                *value = (axial - perpendicular) * u[i] * u[j];
+            }
+            column[i] += perpendicular;
+        }
+        columns[3][3] = 1.0;
+
+        Matrix4::from_2d_array(columns)
+    }
+
+    /// The distances along a ray, in multiples of the ray direction, at which it enters and
+    /// leaves the infinite cylinder around the axis of this capsule, ignoring the caps.
+    ///
+    /// Either distance may be negative if the crossing lies behind the origin of the ray. A ray
+    /// parallel to the axis never crosses the cylinder, so returns `None` if it lies outside the
+    /// radius, and an interval from negative to positive infinity if it lies inside. Capsules
+    /// with a zero length axis have no cylinder, and always return `None`.
+    pub fn ray_cylinder_intersections(&self, ray: &Ray) -> Option<(f32, f32)> {
+        let axis = self.axis.end - self.axis.start;
+        let length_squared = axis.magnitude_squared();
+        if length_squared.sqrt() <= LINEAR_EPSILON * scale_of(&[self.axis.start, self.axis.end]) {
+            return None;
+        }
+
+        // ignore motion along the axis
+        let m = ray.origin - self.axis.start;
+        let m_perp = m - axis * (m.dot(axis) / length_squared);
+        let d_perp = ray.direction - axis * (ray.direction.dot(axis) / length_squared);
+
+        let a = d_perp.dot(d_perp);
+        let b = m_perp.dot(d_perp);
+        let c = m_perp.dot(m_perp) - self.radius * self.radius;
+
+        if a <= ANGULAR_EPSILON * ANGULAR_EPSILON * ray.direction.magnitude_squared() {
+            return (c <= 0.0).then_some((f32::NEG_INFINITY, f32::INFINITY));
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        Some(((-b - root) / a, (-b + root) / a))
+    }
+
+    /// The parameters of the closest points between the axes of this capsule and another, as (on
+    /// self, on other), each clamped to the range 0 to 1 from the start to the end of the axis
+    pub fn closest_axis_params(&self, other: &Capsule) -> (f32, f32) {
+        self.axis.closest_parameters(&other.axis)
+    }
+
+    /// The closest points on the surface of this capsule and another, as (on self, on other).
+    ///
+    /// If the capsules overlap, the surface points cross over, and each lies inside the other capsule.
+    pub fn closest_points(&self, other: &Capsule) -> (Point, Point) {
+        let (s, t) = self.closest_axis_params(other);
+        let p = self.axis.point_at(s);
+        let q = other.axis.point_at(t);
+
+        let normal = self.separating_normal(other, q - p);
+        (p + normal * self.radius, q - normal * other.radius)
+    }
+
+    /// The unit direction from this capsule towards another, given the difference between their
+    /// closest axis points, with a stable fallback when the axes touch
+    pub(crate) fn separating_normal(&self, other: &Capsule, diff: Vector3) -> Vector3 {
+        let tolerance = LINEAR_EPSILON
+            * scale_of(&[
+                self.axis.start,
+                self.axis.end,
+                other.axis.start,
+                other.axis.end,
+            ]);
+        let distance = diff.magnitude();
+        if distance > tolerance {
+            return diff / distance;
+        }
+
+        // the axes cross, so separate perpendicular to both of them
+        let d1 = self.axis.end - self.axis.start;
+        let d2 = other.axis.end - other.axis.start;
+        let cross = d1.cross(d2);
+        if cross.magnitude_squared()
+            > ANGULAR_EPSILON * ANGULAR_EPSILON * d1.magnitude_squared() * d2.magnitude_squared()
+        {
+            return cross.normalized();
+        }
+
+        // the axes are parallel, so separate perpendicular to either of them
+        if d1.magnitude() > tolerance {
+            any_perpendicular(d1)
+        } else {
+            any_perpendicular(d2)
+        }
+    }
+
+    /// Line segments approximating the surface of the capsule, for debug drawing.
+    ///
+    /// This produces a circle of `segments` around each end of the cylinder, `segments` lines
+    /// along the side, and `segments` arcs over each cap, each made from `max(segments / 4, 1)`
+    /// pieces, for a total of `segments * (3 + 2 * max(segments / 4, 1))` line segments.
+    pub fn wireframe(&self, segments: u32) -> Vec<LineSegment> {
+        let d = self.axis.direction().unwrap_or(Vector3::new(0.0, 1.0, 0.0));
+        let u = any_perpendicular(d);
+        let v = d.cross(u);
+
+        let steps = (segments / 4).max(1);
+        let around = |segment: u32| {
+            let theta = std::f32::consts::TAU * segment as f32 / segments as f32;
+            u * theta.cos() + v * theta.sin()
+        };
+        let arc = |center: Point, pole: Vector3, segment: u32, step: u32| {
+            let phi = std::f32::consts::FRAC_PI_2 * step as f32 / steps as f32;
+            center + (around(segment) * phi.cos() + pole * phi.sin()) * self.radius
+        };
+
+        let mut result = vec![];
+        for segment in 0..segments {
+            let r0 = around(segment) * self.radius;
+            let r1 = around(segment + 1) * self.radius;
+            result.push(LineSegment::new(self.axis.start + r0, self.axis.start + r1));
+            result.push(LineSegment::new(self.axis.end + r0, self.axis.end + r1));
+            result.push(LineSegment::new(self.axis.start + r0, self.axis.end + r0));
+
+            for step in 0..steps {
+                result.push(LineSegment::new(
+                    arc(self.axis.start, -d, segment, step),
+                    arc(self.axis.start, -d, segment, step + 1),
+                ));
+                result.push(LineSegment::new(
+                    arc(self.axis.end, d, segment, step),
+                    arc(self.axis.end, d, segment, step + 1),
+                ));
+            }
+        }
+        result
+    }
+
+    /// The spheres at either end of the capsule, centered on the end points of the axis
+    pub fn cap_spheres(&self) -> [Sphere; 2] {
+        [
+            Sphere::new(self.axis.start, self.radius),
+            Sphere::new(self.axis.end, self.radius),
+        ]
+    }
+
+    /// The closest points on the surface of this capsule and a sphere, as (on self, on sphere).
+    ///
+    /// If the shapes overlap, the surface points cross over, and each lies inside the other shape.
+    pub fn closest_points_to_sphere(&self, sphere: &Sphere) -> (Point, Point) {
+        let p = self.axis.closest_point(&sphere.center);
+        let diff = sphere.center - p;
+        let distance = diff.magnitude();
+
+        let normal = if distance > LINEAR_EPSILON * scale_of(&[p, sphere.center]) {
+            diff / distance
+        } else {
+            // the center lies on the axis, so separate perpendicular to it, or along a fixed
+            // direction if the axis has no length
+            any_perpendicular(self.axis.direction().unwrap_or(Vector3::new(0.0, 1.0, 0.0)))
+        };
+        (
+            p + normal * self.radius,
+            sphere.center - normal * sphere.radius,
+        )
+    }
+
+    /// The closest point on the surface of the capsule to another point, and the region of the
+    /// surface on which it lies.
+    ///
+    /// Points on the caps lie radially out from the ends of the axis, and points on the side lie
+    /// radially out from the axis itself. Points on the axis lie in an arbitrary direction
+    /// perpendicular to it.
+    pub fn closest_point_region(&self, p: Point) -> (Point, CapsuleRegion) {
+        let axis = self.axis.end - self.axis.start;
+        let length_squared = axis.magnitude_squared();
+        let t = if length_squared.sqrt()
+            > LINEAR_EPSILON * scale_of(&[self.axis.start, self.axis.end])
+        {
+            (p - self.axis.start).dot(axis) / length_squared
+        } else {
+            0.0
+        };
+
+        let (center, region) = if t <= 0.0 {
+            (self.axis.start, CapsuleRegion::CapA)
+        } else if t >= 1.0 {
+            (self.axis.end, CapsuleRegion::CapB)
+        } else {
+            (self.axis.point_at(t), CapsuleRegion::Side)
+        };
+
+        let direction = safe_normalized(p - center, scale_of(&[p, center])).unwrap_or_else(|| {
+            any_perpendicular(self.axis.direction().unwrap_or(Vector3::new(0.0, 1.0, 0.0)))
+        });
+        (center + direction * self.radius, region)
+    }
+}
+
+/// An arbitrary unit vector perpendicular to the given non-zero vector
+pub(crate) fn any_perpendicular(v: Vector3) -> Vector3 {
+    // crossing with the axis along which the vector is shortest keeps the result well away from zero
+    let (x, y, z) = (v.x.abs(), v.y.abs(), v.z.abs());
+    let axis = if x <= y && x <= z {
+        Vector3::new(1.0, 0.0, 0.0)
+    } else if y <= z {
+        Vector3::new(0.0, 1.0, 0.0)
+    } else {
+        Vector3::new(0.0, 0.0, 1.0)
+    };
+    v.cross(axis).normalized()
+}
+
+impl Default for Capsule {
+    /// A capsule of unit radius around a unit axis from the origin along +Z
+    fn default() -> Self {
+        Self {
+            axis: LineSegment::default(),
+            radius: 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_points_skew() {
+        let a = Capsule::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 0.5);
+        let b = Capsule::new(Point::new(0.0, 3.0, -1.0), Point::new(0.0, 3.0, 1.0), 1.0);
+
+        assert_eq!(
+            a.closest_points(&b),
+            (Point::new(0.0, 0.5, 0.0), Point::new(0.0, 2.0, 0.0))
+        );
+        assert_eq!(
+            b.closest_points(&a),
+            (Point::new(0.0, 2.0, 0.0), Point::new(0.0, 0.5, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_closest_points_parallel() {
+        let a = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 2.0), 0.5);
+        let b = Capsule::new(Point::new(2.0, 0.0, 1.0), Point::new(2.0, 0.0, 3.0), 0.5);
+
+        let (p, q) = a.closest_points(&b);
+        assert_eq!(p.x, 0.5);
+        assert_eq!(q.x, 1.5);
+        assert_eq!(p.z, q.z);
+        assert!(p.z >= 1.0 && p.z <= 2.0);
+    }
+
+    #[test]
+    fn test_closest_points_overlapping() {
+        let a = Capsule::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 1.0);
+        let b = Capsule::new(Point::new(0.0, 1.5, -1.0), Point::new(0.0, 1.5, 1.0), 1.0);
+
+        // the surface points cross over
+        assert_eq!(
+            a.closest_points(&b),
+            (Point::new(0.0, 1.0, 0.0), Point::new(0.0, 0.5, 0.0))
+        );
+
+        // crossing axes separate perpendicular to both
+        let b = Capsule::new(Point::new(0.0, 0.0, -1.0), Point::new(0.0, 0.0, 1.0), 1.0);
+        let (p, q) = a.closest_points(&b);
+        assert_eq!(p.x, 0.0);
+        assert_eq!(p.z, 0.0);
+        assert_eq!(p.y.abs(), 1.0);
+        assert_eq!(q.y, -p.y);
+    }
+
+    #[test]
+    fn test_default() {
+        let capsule = Capsule::default();
+        assert_eq!(capsule.axis.start, Point::zero());
+        assert_eq!(capsule.axis.end, Point::new(0.0, 0.0, 1.0));
+        assert_eq!(capsule.radius, 1.0);
+    }
+
+    #[test]
+    fn test_wireframe() {
+        use crate::Distance;
+
+        let capsule = Capsule::new(Point::new(1.0, 0.0, 0.0), Point::new(2.0, 3.0, -1.0), 0.5);
+
+        let wireframe = capsule.wireframe(8);
+        assert_eq!(wireframe.len(), 8 * (3 + 2 * 2));
+
+        for segment in &wireframe {
+            assert!((capsule.axis.distance(&segment.start) - capsule.radius).abs() < 1e-5);
+            assert!((capsule.axis.distance(&segment.end) - capsule.radius).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Capsule::try_new(Point::zero(), Point::new(0.0, 1.0, 0.0), 0.5).is_ok());
+        assert_eq!(
+            Capsule::try_new(Point::zero(), Point::new(0.0, f32::NAN, 0.0), 0.5).unwrap_err(),
+            ShapeError::NonFinite
+        );
+        assert_eq!(
+            Capsule::try_new(Point::zero(), Point::new(0.0, 1.0, 0.0), f32::NAN).unwrap_err(),
+            ShapeError::NonFinite
+        );
+    }
+
+    #[test]
+    fn test_mass_properties() {
+        let capsule = Capsule::new(Point::zero(), Point::new(0.0, 2.0, 0.0), 1.0);
+        assert!((capsule.surface_area() - 8.0 * std::f32::consts::PI).abs() < 1e-5);
+
+        // spinning about the axis is easier than tumbling end over end
+        let tensor = capsule.inertia_tensor(1.0);
+        let axial = tensor * Vector3::new(0.0, 1.0, 0.0);
+        let perpendicular = tensor * Vector3::new(1.0, 0.0, 0.0);
+        assert!(axial.x.abs() < 1e-6 && axial.z.abs() < 1e-6);
+        assert!(perpendicular.y.abs() < 1e-6 && perpendicular.z.abs() < 1e-6);
+        assert!(axial.y < perpendicular.x);
+
+        // the same capsule along a different axis has the same moments along that axis
+        let rotated = Capsule::new(Point::zero(), Point::new(2.0, 0.0, 0.0), 1.0);
+        let tensor = rotated.inertia_tensor(1.0);
+        assert!(
+            (tensor * Vector3::new(1.0, 0.0, 0.0) - Vector3::new(axial.y, 0.0, 0.0)).magnitude()
+                < 1e-6
+        );
+
+        // a capsule with no length is a sphere
+        let capsule = Capsule::new(Point::new(1.0, 2.0, 3.0), Point::new(1.0, 2.0, 3.0), 2.0);
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        assert_eq!(capsule.surface_area(), sphere.surface_area());
+        let tensor = capsule.inertia_tensor(5.0);
+        let expected = sphere.inertia_tensor(5.0);
+        for i in 0..4 {
+            assert!((tensor.column(i) - expected.column(i)).magnitude() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_cap_spheres() {
+        let capsule = Capsule::new(Point::new(1.0, 0.0, 0.0), Point::new(2.0, 3.0, -1.0), 0.5);
+
+        let [start, end] = capsule.cap_spheres();
+        assert_eq!(start, Sphere::new(Point::new(1.0, 0.0, 0.0), 0.5));
+        assert_eq!(end, Sphere::new(Point::new(2.0, 3.0, -1.0), 0.5));
+    }
+
+    #[test]
+    fn test_closest_points_to_sphere() {
+        let capsule = Capsule::new(Point::new(0.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0), 0.5);
+
+        // near the side
+        let sphere = Sphere::new(Point::new(3.0, 0.5, 0.0), 1.0);
+        let (p, q) = capsule.closest_points_to_sphere(&sphere);
+        assert_eq!(p, Point::new(0.5, 0.5, 0.0));
+        assert_eq!(q, Point::new(2.0, 0.5, 0.0));
+
+        // near the top cap
+        let sphere = Sphere::new(Point::new(0.0, 4.0, 0.0), 1.0);
+        let (p, q) = capsule.closest_points_to_sphere(&sphere);
+        assert_eq!(p, Point::new(0.0, 1.5, 0.0));
+        assert_eq!(q, Point::new(0.0, 3.0, 0.0));
+
+        // centered on the axis, so any direction perpendicular to it will do
+        let sphere = Sphere::new(Point::zero(), 1.0);
+        let (p, q) = capsule.closest_points_to_sphere(&sphere);
+        assert!(((p - Point::zero()).magnitude() - 0.5).abs() < 1e-6);
+        assert!(((q - Point::zero()).magnitude() - 1.0).abs() < 1e-6);
+        assert!(p.y.abs() < 1e-6 && q.y.abs() < 1e-6);
+        assert!((p - Point::zero()).dot(q - Point::zero()) < 0.0);
+
+        // concentric with a capsule whose axis has no length, which is just a sphere
+        let capsule = Capsule::new(Point::new(1.0, 2.0, 3.0), Point::new(1.0, 2.0, 3.0), 0.5);
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 1.0);
+        let (p, q) = capsule.closest_points_to_sphere(&sphere);
+        assert!(((p - capsule.axis.start).magnitude() - 0.5).abs() < 1e-6);
+        assert!(((q - sphere.center).magnitude() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_closest_axis_params() {
+        let a = Capsule::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 0.5);
+
+        // skew, crossing above a quarter of the way along a
+        let b = Capsule::new(Point::new(-0.5, 2.0, -1.0), Point::new(-0.5, 2.0, 3.0), 0.5);
+        let (s, t) = a.closest_axis_params(&b);
+        assert_eq!((s, t), (0.25, 0.25));
+        assert_eq!(a.axis.point_at(s), Point::new(-0.5, 0.0, 0.0));
+        assert_eq!(b.axis.point_at(t), Point::new(-0.5, 2.0, 0.0));
+
+        let (p, q) = a.closest_points(&b);
+        assert_eq!(p, a.axis.point_at(s) + Vector3::new(0.0, 0.5, 0.0));
+        assert_eq!(q, b.axis.point_at(t) - Vector3::new(0.0, 0.5, 0.0));
+
+        // parallel and overlapping along their length, so any pair within the overlap will do
+        let b = Capsule::new(Point::new(0.0, 2.0, 0.0), Point::new(4.0, 2.0, 0.0), 0.5);
+        let (s, t) = a.closest_axis_params(&b);
+        assert!((0.5..=1.0).contains(&s) && (0.0..=0.25).contains(&t));
+        let (p, q) = (a.axis.point_at(s), b.axis.point_at(t));
+        assert!((p.x - q.x).abs() < 1e-6);
+        assert!(((q - p).magnitude() - 2.0).abs() < 1e-6);
+
+        // parallel and disjoint, so clamped to the nearest ends
+        let b = Capsule::new(Point::new(3.0, 2.0, 0.0), Point::new(5.0, 2.0, 0.0), 0.5);
+        assert_eq!(a.closest_axis_params(&b), (1.0, 0.0));
+    }
+
+    #[test]
+    fn test_ray_cylinder_intersections() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 2.0, 0.0), 1.0);
+
+        // crossing the side twice, far beyond the ends of the axis
+        let ray = Ray::new(Point::new(-5.0, 10.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(capsule.ray_cylinder_intersections(&ray), Some((4.0, 6.0)));
+
+        // starting inside, the entry lies behind the ray
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 2.0));
+        assert_eq!(capsule.ray_cylinder_intersections(&ray), Some((-0.5, 0.5)));
+
+        // missing the side entirely
+        let ray = Ray::new(Point::new(-5.0, 1.0, 2.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(capsule.ray_cylinder_intersections(&ray), None);
+
+        // parallel to the axis, inside and outside of the radius
+        let ray = Ray::new(Point::new(0.5, -3.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            capsule.ray_cylinder_intersections(&ray),
+            Some((f32::NEG_INFINITY, f32::INFINITY))
+        );
+        let ray = Ray::new(Point::new(1.5, -3.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(capsule.ray_cylinder_intersections(&ray), None);
+    }
+
+    #[test]
+    fn test_closest_point_region() {
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 4.0, 0.0), 1.0);
+
+        // beyond cap A, off to one side so the point is radial from the end of the axis
+        let (p, region) = capsule.closest_point_region(Point::new(3.0, -4.0, 0.0));
+        assert_eq!(region, CapsuleRegion::CapA);
+        assert!((p - Point::new(0.6, -0.8, 0.0)).magnitude() < 1e-6);
+
+        // beside the middle
+        let (p, region) = capsule.closest_point_region(Point::new(0.0, 2.0, -3.0));
+        assert_eq!(region, CapsuleRegion::Side);
+        assert_eq!(p, Point::new(0.0, 2.0, -1.0));
+
+        // beyond cap B, along the axis
+        let (p, region) = capsule.closest_point_region(Point::new(0.0, 7.0, 0.0));
+        assert_eq!(region, CapsuleRegion::CapB);
+        assert_eq!(p, Point::new(0.0, 5.0, 0.0));
+
+        // on the axis, where any point around the side will do
+        let (p, region) = capsule.closest_point_region(Point::new(0.0, 1.0, 0.0));
+        assert_eq!(region, CapsuleRegion::Side);
+        assert!((capsule.axis.distance(&p) - 1.0).abs() < 1e-6);
+        assert!((p.y - 1.0).abs() < 1e-6);
+    }
 }
@@ -0,0 +1,144 @@
+use std::ops::RangeInclusive;
+
+use mini_math::{Point, Vector3};
+
+use crate::{closest_parameters, Line, LineSegment, Ray};
+
+/// Trait for finding the closest points between two objects, along with the distance between them
+pub trait ClosestPair<Other> {
+    /// The closest point on this object, the closest point on the other, and the distance
+    /// between them
+    fn closest_pair(&self, other: &Other) -> (Point, Point, f32);
+}
+
+/// A parametric line `p + d * s`, with the parameter limited to a range
+type Parametric = (Point, Vector3, RangeInclusive<f32>);
+
+fn line(line: &Line) -> Parametric {
+    (
+        line.point,
+        line.direction,
+        f32::NEG_INFINITY..=f32::INFINITY,
+    )
+}
+
+fn ray(ray: &Ray) -> Parametric {
+    (ray.origin, ray.direction, 0.0..=f32::INFINITY)
+}
+
+fn segment(segment: &LineSegment) -> Parametric {
+    (segment.start, segment.end - segment.start, 0.0..=1.0)
+}
+
+fn closest_pair((p1, d1, range1): Parametric, (p2, d2, range2): Parametric) -> (Point, Point, f32) {
+    let (s, t) = closest_parameters(p1, d1, range1, p2, d2, range2);
+    let a = p1 + d1 * s;
+    let b = p2 + d2 * t;
+    (a, b, (b - a).magnitude())
+}
+
+impl ClosestPair<Line> for Line {
+    fn closest_pair(&self, other: &Line) -> (Point, Point, f32) {
+        closest_pair(line(self), line(other))
+    }
+}
+
+impl ClosestPair<Ray> for Line {
+    fn closest_pair(&self, other: &Ray) -> (Point, Point, f32) {
+        closest_pair(line(self), ray(other))
+    }
+}
+
+impl ClosestPair<LineSegment> for Line {
+    fn closest_pair(&self, other: &LineSegment) -> (Point, Point, f32) {
+        closest_pair(line(self), segment(other))
+    }
+}
+
+impl ClosestPair<Line> for Ray {
+    fn closest_pair(&self, other: &Line) -> (Point, Point, f32) {
+        closest_pair(ray(self), line(other))
+    }
+}
+
+impl ClosestPair<Ray> for Ray {
+    fn closest_pair(&self, other: &Ray) -> (Point, Point, f32) {
+        closest_pair(ray(self), ray(other))
+    }
+}
+
+impl ClosestPair<LineSegment> for Ray {
+    fn closest_pair(&self, other: &LineSegment) -> (Point, Point, f32) {
+        closest_pair(ray(self), segment(other))
+    }
+}
+
+impl ClosestPair<Line> for LineSegment {
+    fn closest_pair(&self, other: &Line) -> (Point, Point, f32) {
+        closest_pair(segment(self), line(other))
+    }
+}
+
+impl ClosestPair<Ray> for LineSegment {
+    fn closest_pair(&self, other: &Ray) -> (Point, Point, f32) {
+        closest_pair(segment(self), ray(other))
+    }
+}
+
+impl ClosestPair<LineSegment> for LineSegment {
+    fn closest_pair(&self, other: &LineSegment) -> (Point, Point, f32) {
+        closest_pair(segment(self), segment(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::NearlyEqual;
+
+    #[test]
+    fn test_segment_segment() {
+        let a = LineSegment::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0));
+
+        // crossing above the middle of the first segment
+        let b = LineSegment::new(Point::new(0.0, 2.0, -1.0), Point::new(0.0, 2.0, 1.0));
+        let (p, q, distance) = a.closest_pair(&b);
+        assert!(p.nearly_equals(&Point::zero()));
+        assert!(q.nearly_equals(&Point::new(0.0, 2.0, 0.0)));
+        assert_eq!(distance, (q - p).magnitude());
+        assert_eq!(distance, 2.0);
+
+        // clamped to the ends of both segments
+        let b = LineSegment::new(Point::new(3.0, 1.0, 2.0), Point::new(3.0, 1.0, 5.0));
+        let (p, q, distance) = a.closest_pair(&b);
+        assert!(p.nearly_equals(&Point::new(1.0, 0.0, 0.0)));
+        assert!(q.nearly_equals(&Point::new(3.0, 1.0, 2.0)));
+        assert_eq!(distance, (q - p).magnitude());
+        assert_eq!(distance, 3.0);
+    }
+
+    #[test]
+    fn test_ray_line() {
+        let ray = Ray::new(Point::zero(), Vector3::new(1.0, 0.0, 0.0));
+
+        // the line passes over the ray
+        let line = Line::new(Point::new(2.0, 1.0, 5.0), Vector3::new(0.0, 0.0, 1.0));
+        let (p, q, distance) = ray.closest_pair(&line);
+        assert!(p.nearly_equals(&Point::new(2.0, 0.0, 0.0)));
+        assert!(q.nearly_equals(&Point::new(2.0, 1.0, 0.0)));
+        assert_eq!(distance, (q - p).magnitude());
+        assert_eq!(distance, 1.0);
+
+        // the line passes behind the ray, and extends in both directions
+        let line = Line::new(Point::new(-2.0, 1.0, 5.0), Vector3::new(0.0, 0.0, 1.0));
+        let (p, q, distance) = ray.closest_pair(&line);
+        assert!(p.nearly_equals(&Point::zero()));
+        assert!(q.nearly_equals(&Point::new(-2.0, 1.0, 0.0)));
+        assert_eq!(distance, (q - p).magnitude());
+
+        let (q2, p2, distance2) = line.closest_pair(&ray);
+        assert!(p2.nearly_equals(&p));
+        assert!(q2.nearly_equals(&q));
+        assert!((distance2 - distance).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,11 @@
+use std::ops::RangeInclusive;
+
 use mini_math::{Point, Vector3};
 
-use crate::{Capsule, Distance, Line, LineSegment, Plane, Ray, Sphere, Triangle};
+use crate::{
+    nearly_parallel, scale_of, Aabb, Capsule, Line, LineSegment, Plane, Ray, Sphere, Triangle,
+    ANGULAR_EPSILON, LINEAR_EPSILON,
+};
 
 /// Trait for finding the closest point to another object
 pub trait ClosestPoint<Other> {
@@ -8,9 +13,78 @@ pub trait ClosestPoint<Other> {
     fn closest_point(&self, other: &Other) -> Point;
 }
 
+/// Parameters of the closest points between two parametric lines `p + d * s`, where each
+/// parameter is clamped to the given range, which must contain zero.
+///
+/// This follows the segment-segment approach from Real-Time Collision Detection, generalised so
+/// that infinite bounds yield rays and lines.
+pub(crate) fn closest_parameters(
+    p1: Point,
+    d1: Vector3,
+    range1: RangeInclusive<f32>,
+    p2: Point,
+    d2: Vector3,
+    range2: RangeInclusive<f32>,
+) -> (f32, f32) {
+    let (min1, max1) = (*range1.start(), *range1.end());
+    let (min2, max2) = (*range2.start(), *range2.end());
+
+    let r = p1 - p2;
+    let a = d1.magnitude_squared();
+    let e = d2.magnitude_squared();
+    let f = d2.dot(r);
+
+    let tolerance = LINEAR_EPSILON * scale_of(&[p1, p1 + d1, p2, p2 + d2]);
+    let degenerate1 = a.sqrt() <= tolerance;
+    let degenerate2 = e.sqrt() <= tolerance;
+
+    // both lines degenerate into points
+    if degenerate1 && degenerate2 {
+        return (0.0, 0.0);
+    }
+
+    // the first line degenerates into a point
+    if degenerate1 {
+        return (0.0, (f / e).clamp(min2, max2));
+    }
+
+    let c = d1.dot(r);
+
+    // the second line degenerates into a point
+    if degenerate2 {
+        return ((-c / a).clamp(min1, max1), 0.0);
+    }
+
+    let b = d1.dot(d2);
+    // a e - b², computed from the cross product to stay precise for nearly parallel lines
+    let denom = d1.cross(d2).magnitude_squared();
+
+    // pick an arbitrary point on the first line if the lines are parallel
+    let s = if denom > ANGULAR_EPSILON * ANGULAR_EPSILON * a * e {
+        ((b * f - c * e) / denom).clamp(min1, max1)
+    } else {
+        0.0
+    };
+
+    let t = (b * s + f) / e;
+    if t < min2 {
+        (((b * min2 - c) / a).clamp(min1, max1), min2)
+    } else if t > max2 {
+        (((b * max2 - c) / a).clamp(min1, max1), max2)
+    } else {
+        (s, t)
+    }
+}
+
 impl ClosestPoint<Point> for Sphere {
+    /// Every surface point is equally close to the center, so querying the exact center
+    /// returns the surface point along +X.
     fn closest_point(&self, other: &Point) -> Point {
-        self.center + (*other - self.center).normalized() * self.radius
+        let diff = *other - self.center;
+        if diff.magnitude_squared() == 0.0 {
+            return self.center + Vector3::new(self.radius, 0.0, 0.0);
+        }
+        self.center + diff.normalized() * self.radius
     }
 }
 
@@ -35,7 +109,9 @@ impl ClosestPoint<Line> for Line {
         let e = other.direction.dot(w);
         let d_p = 1.0 - b * b;
 
-        if d_p < std::f32::EPSILON {
+        // the cross product measures small angles far more precisely than 1 - b²
+        let sine_squared = self.direction.cross(other.direction).magnitude_squared();
+        if sine_squared <= ANGULAR_EPSILON * ANGULAR_EPSILON {
             return self.point;
         }
 
@@ -127,9 +203,12 @@ impl ClosestPoint<Ray> for Ray {
 
 impl ClosestPoint<Point> for LineSegment {
     fn closest_point(&self, other: &Point) -> Point {
-        let mut direction = self.end - self.start;
-        let length = direction.magnitude();
-        direction /= length;
+        // a segment too short to have a direction is just its start point
+        let direction = match self.direction() {
+            Some(direction) => direction,
+            None => return self.start,
+        };
+        let length = (self.end - self.start).magnitude();
 
         let dot = (*other - self.start).dot(direction);
 
@@ -159,7 +238,7 @@ impl ClosestPoint<LineSegment> for LineSegment {
 
 impl ClosestPoint<Point> for Plane {
     fn closest_point(&self, other: &Point) -> Point {
-        let distance = self.distance(other);
+        let distance = self.signed_distance(other);
         *other - self.normal * distance
     }
 }
@@ -168,7 +247,7 @@ impl ClosestPoint<Ray> for Plane {
     fn closest_point(&self, other: &Ray) -> Point {
         let n_dot_r = self.normal.dot(other.direction);
         // early exit if ray parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, other.direction, ANGULAR_EPSILON) {
             return self.closest_point(&other.origin);
         }
 
@@ -181,39 +260,17 @@ impl ClosestPoint<Ray> for Plane {
 
 impl ClosestPoint<Point> for Triangle {
     fn closest_point(&self, other: &Point) -> Point {
-        let plane = Plane::from(self);
-        let q = plane.closest_point(other);
-
-        let coordinates = self.barycentric_coordinates(q);
-        if coordinates.x >= 0.0 && coordinates.y >= 0.0 && coordinates.z >= 0.0 {
-            return q;
-        }
-
-        let p0 = LineSegment::new(self.a, self.b).closest_point(other);
-        let p1 = LineSegment::new(self.b, self.c).closest_point(other);
-        let p2 = LineSegment::new(self.c, self.a).closest_point(other);
-
-        let d0 = (p0 - *other).magnitude_squared();
-        let d1 = (p1 - *other).magnitude_squared();
-        let d2 = (p2 - *other).magnitude_squared();
-
-        if d0 < d1 && d0 < d2 {
-            p0
-        } else if d1 < d0 && d1 < d2 {
-            p1
-        } else {
-            p2
-        }
+        self.closest_feature(*other).1
     }
 }
 
 impl ClosestPoint<Ray> for Triangle {
     fn closest_point(&self, other: &Ray) -> Point {
-        let plane = Plane::from(self);
+        let plane = self.plane();
 
         let n_dot_r = plane.normal.dot(other.direction);
         // early exit if ray parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, other.direction, ANGULAR_EPSILON) {
             return self.closest_point(&other.origin);
         }
 
@@ -225,12 +282,36 @@ impl ClosestPoint<Ray> for Triangle {
     }
 }
 
+impl ClosestPoint<Point> for Aabb {
+    /// Points inside the box are their own closest point
+    fn closest_point(&self, other: &Point) -> Point {
+        other.max(self.min).min(self.max)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use mini_math::Vector3;
 
     use super::*;
 
+    #[test]
+    fn test_sphere_point() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+
+        let p = Point::new(1.0, 7.0, 3.0);
+        assert_eq!(sphere.closest_point(&p), Point::new(1.0, 4.0, 3.0));
+
+        let p = Point::new(1.0, 2.5, 3.0);
+        assert_eq!(sphere.closest_point(&p), Point::new(1.0, 4.0, 3.0));
+
+        // the center falls back to a fixed surface point
+        assert_eq!(
+            sphere.closest_point(&sphere.center),
+            Point::new(3.0, 2.0, 3.0)
+        );
+    }
+
     #[test]
     fn test_line_line() {
         let line = Line::from_points(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0));
@@ -308,4 +389,63 @@ mod tests {
         let p = Point::new(0.0, -1.0, -2.0);
         assert_eq!(triangle.closest_point(&p), Point::new(0.0, 0.0, -1.0));
     }
+
+    #[test]
+    fn test_triangle_point_vertex_tie() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // beyond vertex b, where edges a-b and b-c are equidistant
+        let p = Point::new(2.0, 1.0, -2.0);
+        assert_eq!(triangle.closest_point(&p), Point::new(1.0, 0.0, -1.0));
+
+        // beyond vertex a, where edges a-b and c-a are equidistant
+        let p = Point::new(-2.0, 1.0, -2.0);
+        assert_eq!(triangle.closest_point(&p), Point::new(-1.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn test_aabb_point() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+        let p = Point::new(0.5, -0.25, 0.0);
+        assert_eq!(aabb.closest_point(&p), p);
+        assert_eq!(
+            aabb.closest_point(&Point::new(3.0, 0.5, 0.0)),
+            Point::new(1.0, 0.5, 0.0)
+        );
+        assert_eq!(
+            aabb.closest_point(&Point::new(-3.0, 5.0, -2.0)),
+            Point::new(-1.0, 1.0, -1.0)
+        );
+    }
+
+    #[test]
+    fn test_closest_parameters_small_scale() {
+        // segments a fraction of a millimetre long are not degenerate near the origin
+        let (s, t) = closest_parameters(
+            Point::new(0.0, 0.0, 0.0),
+            Vector3::new(1e-4, 0.0, 0.0),
+            0.0..=1.0,
+            Point::new(5e-5, 1e-4, 1e-5),
+            Vector3::new(0.0, -2e-4, 0.0),
+            0.0..=1.0,
+        );
+        assert!((s - 0.5).abs() < 1e-4);
+        assert!((t - 0.5).abs() < 1e-4);
+
+        // but one within rounding error of its coordinates is
+        let (s, _) = closest_parameters(
+            Point::new(1000.0, 0.0, 0.0),
+            Vector3::new(1e-6, 0.0, 0.0),
+            0.0..=1.0,
+            Point::new(1000.0, 1.0, 0.0),
+            Vector3::new(0.0, -2.0, 0.0),
+            0.0..=1.0,
+        );
+        assert_eq!(s, 0.0);
+    }
 }
@@ -1,39 +1,101 @@
-use crate::{ClosestPoint, LineSegment, Plane, Ray, Sphere, Triangle};
+use crate::{
+    nearly_parallel, scale_of, Aabb, Capsule, ClosestPoint, Feature, Intersection, LineSegment,
+    Plane, Ray, Sphere, Triangle, ANGULAR_EPSILON, LINEAR_EPSILON,
+};
 use mini_math::{NearlyEqual, Point, Vector3};
 
-/// The result of a collision
+/// The result of a collision, optionally tagged with additional user data
 #[derive(PartialEq, Debug)]
-pub struct Contact {
-    /// The point at which the collision occurs
+pub struct Contact<T = ()> {
+    /// The point at which the collision occurs, midway between the witness points
     pub point: Point,
+    /// The point on the first shape nearest to the second, or deepest within it when they
+    /// overlap. Shapes which meet without overlapping, such as a ray or segment crossing a
+    /// surface, or two triangles, share a single point, so both witness points lie there.
+    pub point_a: Point,
+    /// The point on the second shape nearest to the first, or deepest within it when they
+    /// overlap
+    pub point_b: Point,
     /// The surface normal at the point of collision
     pub normal: Vector3,
     /// The distance by which the colliding shapes overlap
     pub overlap: f32,
+    /// Additional user data attached to the contact
+    pub data: T,
 }
 
-impl NearlyEqual for &Contact {
+impl<T: PartialEq> NearlyEqual for &Contact<T> {
     fn nearly_equals(self, rhs: Self) -> bool {
         self.point.nearly_equals(&rhs.point)
+            && self.point_a.nearly_equals(&rhs.point_a)
+            && self.point_b.nearly_equals(&rhs.point_b)
             && self.normal.nearly_equals(&rhs.normal)
             && self.overlap.nearly_equals(rhs.overlap)
+            && self.data == rhs.data
     }
 }
 
 impl Contact {
+    /// A contact between shapes which meet at a single shared point
     fn new(point: Point, normal: Vector3, overlap: f32) -> Self {
+        Self::with_points(point, point, normal, overlap)
+    }
+
+    pub(crate) fn with_points(
+        point_a: Point,
+        point_b: Point,
+        normal: Vector3,
+        overlap: f32,
+    ) -> Self {
         Self {
-            point,
+            point: point_a + (point_b - point_a) * 0.5,
+            point_a,
+            point_b,
             normal,
             overlap,
+            data: (),
+        }
+    }
+}
+
+impl<T> Contact<T> {
+    /// Replace the user data attached to this contact
+    pub fn with_data<U>(self, data: U) -> Contact<U> {
+        Contact {
+            point: self.point,
+            point_a: self.point_a,
+            point_b: self.point_b,
+            normal: self.normal,
+            overlap: self.overlap,
+            data,
         }
     }
 }
 
 /// Trait for determining the collision between two shapes
 pub trait Collision<Rhs> {
-    /// Whether this shape collides with the other, and where
+    /// Whether this shape collides with the other, and where.
+    ///
+    /// Solid shapes only collide when they overlap, so shapes which merely touch, such as after
+    /// being moved apart by the [`mtv`](Collision::mtv), don't collide.
     fn collides(&self, rhs: &Rhs) -> Option<Contact>;
+
+    /// Whether this shape collides with the other.
+    ///
+    /// This is a fallback for shape pairs which don't provide a cheaper [`Intersection`](crate::Intersection).
+    /// There is no bridge in the other direction, since a boolean intersection test has no
+    /// contact to report, so pairs with only an `Intersection` impl don't get a `Collision`.
+    fn is_colliding(&self, rhs: &Rhs) -> bool {
+        self.collides(rhs).is_some()
+    }
+
+    /// The minimum translation that moves this shape out of contact with the other, if they
+    /// collide, which is the contact normal scaled by the overlap. Once moved, solid shapes are
+    /// left just touching and no longer collide.
+    fn mtv(&self, rhs: &Rhs) -> Option<Vector3> {
+        self.collides(rhs)
+            .map(|contact| contact.normal * contact.overlap)
+    }
 }
 
 impl Collision<Sphere> for Sphere {
@@ -41,13 +103,14 @@ impl Collision<Sphere> for Sphere {
         let combined_radius = self.radius + sphere.radius;
         let diff = self.center - sphere.center;
         let distance_squared = diff.magnitude_squared();
-        if distance_squared > combined_radius * combined_radius {
+        if distance_squared >= combined_radius * combined_radius {
             None
         } else {
             let distance = distance_squared.sqrt();
             let normal = diff / distance;
 
-            Some(Contact::new(
+            Some(Contact::with_points(
+                self.center - normal * self.radius,
                 sphere.center + normal * sphere.radius,
                 normal,
                 combined_radius - distance,
@@ -56,48 +119,336 @@ impl Collision<Sphere> for Sphere {
     }
 }
 
+impl Collision<Plane> for Sphere {
+    fn collides(&self, plane: &Plane) -> Option<Contact> {
+        let distance = plane.signed_distance(&self.center);
+        let overlap = self.radius - distance.abs();
+        if overlap <= 0.0 {
+            return None;
+        }
+
+        // separate towards whichever side of the plane the center lies on
+        let normal = if distance < 0.0 {
+            -plane.normal
+        } else {
+            plane.normal
+        };
+        Some(Contact::with_points(
+            self.center - normal * self.radius,
+            self.center - normal * distance.abs(),
+            normal,
+            overlap,
+        ))
+    }
+}
+
+impl Collision<Sphere> for Plane {
+    fn collides(&self, sphere: &Sphere) -> Option<Contact> {
+        let contact = sphere.collides(self)?;
+        Some(Contact::with_points(
+            contact.point_b,
+            contact.point_a,
+            -contact.normal,
+            contact.overlap,
+        ))
+    }
+}
+
+impl Collision<Aabb> for Sphere {
+    fn collides(&self, aabb: &Aabb) -> Option<Contact> {
+        if !aabb.contains(&self.center) {
+            let q = aabb.closest_point(&self.center);
+            let diff = self.center - q;
+            let distance = diff.magnitude();
+            let overlap = self.radius - distance;
+            if overlap <= 0.0 {
+                return None;
+            }
+
+            let normal = diff / distance;
+            return Some(Contact::with_points(
+                self.center - normal * self.radius,
+                q,
+                normal,
+                overlap,
+            ));
+        }
+
+        // the center is inside the box, so push out through the nearest face
+        let c = self.center;
+        let faces = [
+            (c.x - aabb.min.x, Vector3::new(-1.0, 0.0, 0.0)),
+            (aabb.max.x - c.x, Vector3::new(1.0, 0.0, 0.0)),
+            (c.y - aabb.min.y, Vector3::new(0.0, -1.0, 0.0)),
+            (aabb.max.y - c.y, Vector3::new(0.0, 1.0, 0.0)),
+            (c.z - aabb.min.z, Vector3::new(0.0, 0.0, -1.0)),
+            (aabb.max.z - c.z, Vector3::new(0.0, 0.0, 1.0)),
+        ];
+        let (depth, normal) = faces
+            .into_iter()
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .unwrap();
+
+        Some(Contact::with_points(
+            c - normal * self.radius,
+            c + normal * depth,
+            normal,
+            self.radius + depth,
+        ))
+    }
+}
+
+impl Collision<Sphere> for Aabb {
+    fn collides(&self, sphere: &Sphere) -> Option<Contact> {
+        let contact = sphere.collides(self)?;
+        Some(Contact::with_points(
+            contact.point_b,
+            contact.point_a,
+            -contact.normal,
+            contact.overlap,
+        ))
+    }
+}
+
 impl Collision<Triangle> for Sphere {
     fn collides(&self, triangle: &Triangle) -> Option<Contact> {
-        let plane = Plane::from(triangle);
+        let plane = triangle.plane();
 
         let p = plane.closest_point(&self.center);
         let distance_from_plane_squared = (p - self.center).magnitude_squared();
 
-        if distance_from_plane_squared > self.radius * self.radius {
+        if distance_from_plane_squared >= self.radius * self.radius {
             None
         } else {
             let q = triangle.closest_point(&self.center);
-            let diff = q - self.center;
-            let overlap = self.radius - diff.magnitude();
-            if overlap < 0.0 {
+            let diff = self.center - q;
+            let distance = diff.magnitude();
+            let overlap = self.radius - distance;
+            let tolerance =
+                LINEAR_EPSILON * scale_of(&[self.center, triangle.a, triangle.b, triangle.c]);
+            if overlap <= 0.0 {
                 None
+            } else if (p - q).magnitude() > tolerance && distance > tolerance {
+                // touching an edge or vertex, so separate directly away from that feature
+                let normal = diff / distance;
+                Some(Contact::with_points(
+                    self.center - normal * self.radius,
+                    q,
+                    normal,
+                    overlap,
+                ))
             } else {
-                Some(Contact::new(q, plane.normal, overlap))
+                Some(Contact::with_points(
+                    self.center - plane.normal * self.radius,
+                    q,
+                    plane.normal,
+                    overlap,
+                ))
             }
         }
     }
 }
 
+impl Collision<Sphere> for Ray {
+    fn collides(&self, sphere: &Sphere) -> Option<Contact> {
+        let (a, b, c) = sphere.ray_quadratic(self);
+
+        let discriminant = b * b - a * c;
+        // early exit if ray misses the sphere entirely
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let mut t = (-b - root) / a;
+        // if the ray starts inside the sphere, the first hit is on the way out
+        if t < 0.0 {
+            t = (-b + root) / a;
+        }
+
+        // early exit if sphere entirely behind ray
+        if t < 0.0 {
+            return None;
+        }
+
+        let intersection_point = self.origin + self.direction * t;
+        let normal = (intersection_point - sphere.center) / sphere.radius;
+        Some(Contact::new(intersection_point, normal, 0.0))
+    }
+}
+
+impl Collision<Capsule> for Capsule {
+    fn collides(&self, capsule: &Capsule) -> Option<Contact> {
+        let (s, t) = self.closest_axis_params(capsule);
+        let p = self.axis.point_at(s);
+        let q = capsule.axis.point_at(t);
+
+        let combined_radius = self.radius + capsule.radius;
+        let diff = p - q;
+        let distance_squared = diff.magnitude_squared();
+        if distance_squared >= combined_radius * combined_radius {
+            None
+        } else {
+            let normal = capsule.separating_normal(self, diff);
+
+            Some(Contact::with_points(
+                p - normal * self.radius,
+                q + normal * capsule.radius,
+                normal,
+                combined_radius - distance_squared.sqrt(),
+            ))
+        }
+    }
+}
+
+impl Collision<Triangle> for Capsule {
+    /// The normal points away from the feature of the triangle closest to the axis, which is
+    /// the face normal only when the axis lies over the face, flipped if it lies below it. An
+    /// axis which pierces the triangle is pushed out along the face normal on the side where
+    /// most of the axis lies, far enough to clear its deepest end.
+    ///
+    /// Using the face normal for every contact would be simpler, but beside an edge or vertex
+    /// moving along it by the overlap may leave the shapes colliding, while moving away from the
+    /// closest feature always separates them.
+    fn collides(&self, triangle: &Triangle) -> Option<Contact> {
+        let (q, p) = triangle.closest_points_to_segment(&self.axis);
+        let diff = p - q;
+        let distance = diff.magnitude();
+        let overlap = self.radius - distance;
+        if overlap <= 0.0 {
+            return None;
+        }
+
+        let plane = triangle.plane();
+        let tolerance = LINEAR_EPSILON
+            * scale_of(&[
+                self.axis.start,
+                self.axis.end,
+                triangle.a,
+                triangle.b,
+                triangle.c,
+            ]);
+        if distance <= tolerance {
+            // the axis pierces the triangle, so push the deepest end out of the face
+            let da = plane.signed_distance(&self.axis.start);
+            let db = plane.signed_distance(&self.axis.end);
+            let (normal, deepest, depth) = if da + db >= 0.0 {
+                let (end, d) = if da < db {
+                    (self.axis.start, da)
+                } else {
+                    (self.axis.end, db)
+                };
+                (plane.normal, end, -d)
+            } else {
+                let (end, d) = if da > db {
+                    (self.axis.start, da)
+                } else {
+                    (self.axis.end, db)
+                };
+                (-plane.normal, end, d)
+            };
+
+            let overlap = self.radius + depth;
+            let point_a = deepest - normal * self.radius;
+            return Some(Contact::with_points(
+                point_a,
+                point_a + normal * overlap,
+                normal,
+                overlap,
+            ));
+        }
+
+        let normal = match triangle.closest_feature(p).0 {
+            Feature::Face if plane.signed_distance(&p) < 0.0 => -plane.normal,
+            Feature::Face => plane.normal,
+            Feature::Edge(_) | Feature::Vertex(_) => diff / distance,
+        };
+        Some(Contact::with_points(
+            p - normal * self.radius,
+            q,
+            normal,
+            overlap,
+        ))
+    }
+}
+
+impl Collision<Triangle> for Triangle {
+    /// The contact lies midway along the segment where the triangles cross, with the normal
+    /// along the cross product of their face normals, the direction of that segment. Coplanar
+    /// triangles instead make contact at the middle of their overlap, with the normal of this
+    /// triangle. The overlap is always zero.
+    fn collides(&self, other: &Triangle) -> Option<Contact> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        // touching within tolerance, without any exact crossing
+        let touching = || other.closest_point(&self.centroid());
+
+        let normal = self.plane().normal;
+        let direction = normal.cross(other.plane().normal);
+        let (point, normal) = if direction.magnitude_squared() < ANGULAR_EPSILON * ANGULAR_EPSILON {
+            let points = self.coplanar_overlap_points(other);
+            let point = if points.is_empty() {
+                touching()
+            } else {
+                let sum = points
+                    .iter()
+                    .fold(Vector3::zero(), |sum, p| sum + Vector3::from(*p));
+                Point::from(sum / points.len() as f32)
+            };
+            (point, normal)
+        } else {
+            // the ends of the crossing segment are where edges of one pass through the other
+            let direction = direction.normalized();
+            let crossings = [(other, self.edges()), (self, other.edges())]
+                .into_iter()
+                .flat_map(|(triangle, edges)| {
+                    let plane = triangle.plane();
+                    edges.into_iter().filter_map(move |edge| {
+                        let p = edge.point_at(plane.intersect_segment_t(&edge)?);
+                        triangle.coplanar_point_inside(p).then_some(p)
+                    })
+                });
+
+            let along = |p: Point| Vector3::from(p).dot(direction);
+            let ends = crossings.fold(None, |ends: Option<(Point, Point)>, p| match ends {
+                None => Some((p, p)),
+                Some((first, last)) => Some((
+                    if along(p) < along(first) { p } else { first },
+                    if along(p) > along(last) { p } else { last },
+                )),
+            });
+            let point = match ends {
+                Some((first, last)) => first + (last - first) * 0.5,
+                None => touching(),
+            };
+            (point, direction)
+        };
+
+        Some(Contact::new(point, normal, 0.0))
+    }
+}
+
 impl Collision<Triangle> for Ray {
     fn collides(&self, triangle: &Triangle) -> Option<Contact> {
-        let plane = Plane::from(triangle);
+        let plane = triangle.plane();
 
         let n_dot_r = plane.normal.dot(self.direction);
         // early exit if ray parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, self.direction, ANGULAR_EPSILON) {
             return None;
         }
 
-        let d = plane.normal.dot(Vector3::from(triangle.a));
-        let e = plane.normal.dot(Vector3::from(self.origin));
-        let t = (e + d) / n_dot_r;
+        let d = plane.normal.dot(self.origin - triangle.a);
+        let t = -d / n_dot_r;
 
         // early exit if triangle entirely behind ray
-        if t > 0.0 {
+        if t < 0.0 {
             return None;
         }
 
-        let intersection_point = self.origin + self.direction * -t;
+        let intersection_point = self.origin + self.direction * t;
         if triangle.coplanar_point_inside(intersection_point) {
             Some(Contact::new(intersection_point, plane.normal, 0.0))
         } else {
@@ -108,7 +459,7 @@ impl Collision<Triangle> for Ray {
 
 impl Collision<Triangle> for LineSegment {
     fn collides(&self, triangle: &Triangle) -> Option<Contact> {
-        let plane = Plane::from(triangle);
+        let plane = triangle.plane();
 
         let mut direction = self.end - self.start;
         let length = direction.magnitude();
@@ -116,20 +467,19 @@ impl Collision<Triangle> for LineSegment {
 
         let n_dot_r = plane.normal.dot(direction);
         // early exit if line parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, direction, ANGULAR_EPSILON) {
             return None;
         }
 
-        let d = plane.normal.dot(Vector3::from(triangle.a));
-        let e = plane.normal.dot(Vector3::from(self.start));
-        let t = (e + d) / n_dot_r;
+        let d = plane.normal.dot(self.start - triangle.a);
+        let t = -d / n_dot_r;
 
         // early exit if triangle is entirely in fornt or behind of the line segment
-        if t > 0.0 || t < -length {
+        if t < 0.0 || t > length {
             return None;
         }
 
-        let intersection_point = self.start + direction * -t;
+        let intersection_point = self.start + direction * t;
         if triangle.coplanar_point_inside(intersection_point) {
             Some(Contact::new(intersection_point, plane.normal, 0.0))
         } else {
@@ -138,9 +488,62 @@ impl Collision<Triangle> for LineSegment {
     }
 }
 
+impl Collision<Plane> for Ray {
+    fn collides(&self, plane: &Plane) -> Option<Contact> {
+        let n_dot_r = plane.normal.dot(self.direction);
+        // early exit if ray parallel to plane
+        if nearly_parallel(n_dot_r, self.direction, ANGULAR_EPSILON) {
+            return None;
+        }
+
+        let t = -plane.signed_distance(&self.origin) / n_dot_r;
+
+        // early exit if plane entirely behind ray
+        if t < 0.0 {
+            return None;
+        }
+
+        // the normal faces back towards the incoming ray, whichever side it approaches from
+        let normal = if n_dot_r > 0.0 {
+            -plane.normal
+        } else {
+            plane.normal
+        };
+        Some(Contact::new(self.origin + self.direction * t, normal, 0.0))
+    }
+}
+
+impl Collision<Plane> for LineSegment {
+    fn collides(&self, plane: &Plane) -> Option<Contact> {
+        let direction = self.end - self.start;
+
+        let n_dot_r = plane.normal.dot(direction);
+        // early exit if line parallel to plane
+        if nearly_parallel(n_dot_r, direction, ANGULAR_EPSILON) {
+            return None;
+        }
+
+        let t = -plane.signed_distance(&self.start) / n_dot_r;
+
+        // early exit if plane is entirely in front or behind of the line segment
+        if !(0.0..=1.0).contains(&t) {
+            return None;
+        }
+
+        // the normal faces back towards the start of the segment
+        let normal = if n_dot_r > 0.0 {
+            -plane.normal
+        } else {
+            plane.normal
+        };
+        Some(Contact::new(self.point_at(t), normal, 0.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Distance;
     use mini_math::{Point, Vector3};
 
     #[test]
@@ -150,12 +553,42 @@ mod tests {
 
         assert_eq!(
             b.collides(&a),
-            Some(Contact::new(
+            Some(Contact::with_points(
+                Point::new(0.0, 0.5, 0.0),
                 Point::new(0.0, 1.0, 0.0),
                 Vector3::new(0.0, 1.0, 0.0),
                 0.5
             ))
         );
+
+        // the witness points lie on the surface of each sphere
+        let contact = a.collides(&b).unwrap();
+        assert!(a.signed_distance(&contact.point_a).abs() < 1e-6);
+        assert!(b.signed_distance(&contact.point_b).abs() < 1e-6);
+        assert_eq!(contact.point, Point::new(0.0, 0.75, 0.0));
+    }
+
+    #[test]
+    fn test_contact_with_data() {
+        #[derive(PartialEq, Debug)]
+        struct Material {
+            friction: f32,
+        }
+
+        let a = Sphere::new(Point::zero(), 1.0);
+        let b = Sphere::new(Point::new(0.0, 1.5, 0.0), 1.0);
+
+        let contact = b
+            .collides(&a)
+            .map(|c| c.with_data(Material { friction: 0.5 }))
+            .unwrap();
+
+        assert_eq!(contact.point, Point::new(0.0, 0.75, 0.0));
+        assert_eq!(contact.point_a, Point::new(0.0, 0.5, 0.0));
+        assert_eq!(contact.point_b, Point::new(0.0, 1.0, 0.0));
+        assert_eq!(contact.normal, Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(contact.overlap, 0.5);
+        assert_eq!(contact.data, Material { friction: 0.5 });
     }
 
     #[test]
@@ -169,7 +602,8 @@ mod tests {
 
         assert_eq!(
             b.collides(&a),
-            Some(Contact::new(
+            Some(Contact::with_points(
+                Point::new(0.0, -0.25, 0.0),
                 Point::new(0.0, 0.0, 0.0),
                 Vector3::new(0.0, 1.0, 0.0),
                 0.25
@@ -186,6 +620,185 @@ mod tests {
         assert_eq!(b.collides(&a), None);
     }
 
+    #[test]
+    fn test_sphere_triangle_edge_collision() {
+        let a = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // just beyond the a-b edge, the normal points from the edge to the center
+        let b = Sphere::new(Point::new(0.0, 0.3, -1.4), 0.6);
+        let contact = b.collides(&a).unwrap();
+        assert_eq!(contact.point_b, Point::new(0.0, 0.0, -1.0));
+        assert!(a.distance(&contact.point_b) < 1e-6);
+        assert!(b.signed_distance(&contact.point_a).abs() < 1e-6);
+        assert!(contact.normal.nearly_equals(&Vector3::new(0.0, 0.6, -0.8)));
+        assert!((contact.overlap - 0.1).abs() < 1e-6);
+
+        // just beyond vertex c
+        let b = Sphere::new(Point::new(0.0, 0.0, 1.5), 1.0);
+        let contact = b.collides(&a).unwrap();
+        assert_eq!(contact.point_a, Point::new(0.0, 0.0, 0.5));
+        assert_eq!(contact.point_b, Point::new(0.0, 0.0, 1.0));
+        assert_eq!(contact.point, Point::new(0.0, 0.0, 0.75));
+        assert_eq!(contact.normal, Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(contact.overlap, 0.5);
+    }
+
+    #[test]
+    fn test_capsule_capsule_collision() {
+        let a = Capsule::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 1.0);
+        let b = Capsule::new(Point::new(0.0, 1.5, -1.0), Point::new(0.0, 1.5, 1.0), 1.0);
+
+        assert_eq!(
+            b.collides(&a),
+            Some(Contact::with_points(
+                Point::new(0.0, 0.5, 0.0),
+                Point::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.5
+            ))
+        );
+        assert_eq!(
+            a.collides(&b),
+            Some(Contact::with_points(
+                Point::new(0.0, 1.0, 0.0),
+                Point::new(0.0, 0.5, 0.0),
+                Vector3::new(0.0, -1.0, 0.0),
+                0.5
+            ))
+        );
+
+        // clear of one another
+        let b = Capsule::new(Point::new(0.0, 2.5, -1.0), Point::new(0.0, 2.5, 1.0), 1.0);
+        assert_eq!(a.collides(&b), None);
+
+        // coincident axes still produce a usable normal
+        let b = Capsule::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 0.0, 0.0), 0.5);
+        let contact = a.collides(&b).unwrap();
+        assert!((contact.normal.magnitude() - 1.0).abs() < 1e-6);
+        assert_eq!(contact.normal.dot(Vector3::new(1.0, 0.0, 0.0)), 0.0);
+        assert_eq!(contact.overlap, 1.5);
+    }
+
+    #[test]
+    fn test_capsule_triangle_collision() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+        let check = |capsule: Capsule, point_b: Point, normal: Vector3, overlap: f32| {
+            let contact = capsule.collides(&triangle).unwrap();
+            assert!((contact.point_b - point_b).magnitude() < 1e-6);
+            assert!((contact.normal - normal).magnitude() < 1e-6);
+            assert!((contact.overlap - overlap).abs() < 1e-6);
+
+            // the minimum translation leaves the capsule just touching the triangle
+            let mtv = capsule.mtv(&triangle).unwrap();
+            let moved = Capsule::new(
+                capsule.axis.start + mtv,
+                capsule.axis.end + mtv,
+                capsule.radius,
+            );
+            assert!(moved.collides(&triangle).is_none());
+        };
+
+        // resting on the face
+        let capsule = Capsule::new(Point::new(-0.5, 0.5, 0.0), Point::new(0.5, 1.5, 0.0), 1.0);
+        check(
+            capsule,
+            Point::new(-0.5, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            0.5,
+        );
+
+        // touching the face from below
+        let capsule = Capsule::new(Point::new(-0.5, -0.5, 0.0), Point::new(0.5, -1.5, 0.0), 1.0);
+        check(
+            capsule,
+            Point::new(-0.5, 0.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            0.5,
+        );
+
+        // hanging over an edge, which pushes away from the edge
+        let capsule = Capsule::new(Point::new(0.0, 0.5, -1.5), Point::new(0.0, 2.0, -3.0), 1.0);
+        check(
+            capsule,
+            Point::new(0.0, 0.0, -1.0),
+            Vector3::new(0.0, 1.0, -1.0).normalized(),
+            1.0 - 0.5f32.sqrt(),
+        );
+
+        // poking a vertex
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 1.5), Point::new(0.0, 0.0, 3.0), 1.0);
+        check(
+            capsule.clone(),
+            Point::new(0.0, 0.0, 1.0),
+            Vector3::new(0.0, 0.0, 1.0),
+            0.5,
+        );
+
+        // where moving along the face normal by the same overlap wouldn't separate them
+        let lift = triangle.plane().normal * capsule.collides(&triangle).unwrap().overlap;
+        let lifted = Capsule::new(capsule.axis.start + lift, capsule.axis.end + lift, 1.0);
+        assert!(lifted.collides(&triangle).is_some());
+
+        // piercing the face, mostly above it, must clear the end below the face
+        let capsule = Capsule::new(Point::new(0.0, -0.5, 0.0), Point::new(0.0, 2.0, 0.0), 0.25);
+        let contact = capsule.collides(&triangle).unwrap();
+        assert!((contact.normal - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+        assert!((contact.overlap - 0.75).abs() < 1e-6);
+
+        // and mostly below it pushes downwards
+        let capsule = Capsule::new(Point::new(0.0, 0.5, 0.0), Point::new(0.0, -2.0, 0.0), 0.25);
+        let contact = capsule.collides(&triangle).unwrap();
+        assert!((contact.normal - Vector3::new(0.0, -1.0, 0.0)).magnitude() < 1e-6);
+        assert!((contact.overlap - 0.75).abs() < 1e-6);
+
+        // clear of the triangle
+        let capsule = Capsule::new(Point::new(-0.5, 1.5, 0.0), Point::new(0.5, 1.5, 0.0), 1.0);
+        assert_eq!(capsule.collides(&triangle), None);
+    }
+
+    #[test]
+    fn test_ray_sphere_collision() {
+        let sphere = Sphere::new(Point::zero(), 1.0);
+
+        // frontal
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(
+            ray.collides(&sphere),
+            Some(Contact::new(
+                Point::new(0.0, 0.0, -1.0),
+                Vector3::new(0.0, 0.0, -1.0),
+                0.0
+            ))
+        );
+
+        // tangent
+        let ray = Ray::new(Point::new(0.0, 1.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(
+            ray.collides(&sphere),
+            Some(Contact::new(
+                Point::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.0
+            ))
+        );
+
+        // miss
+        let ray = Ray::new(Point::new(0.0, 2.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.collides(&sphere), None);
+
+        // behind
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(ray.collides(&sphere), None);
+    }
+
     #[test]
     fn test_triangle_ray_collision() {
         let triangle = Triangle::new(
@@ -235,4 +848,276 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_collision_agrees_with_intersection() {
+        use crate::Intersection;
+
+        let triangles = [
+            Triangle::new(
+                Point::new(-1.0, 0.0, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(0.0, 0.0, 1.0),
+            ),
+            Triangle::new(
+                Point::new(-1.0, 1.0, 0.0),
+                Point::new(1.0, 1.0, 0.0),
+                Point::new(0.0, 1.0, 1.0),
+            ),
+            Triangle::new(
+                Point::new(2.0, 0.0, -1.25),
+                Point::new(0.0, 3.0, 2.5),
+                Point::new(-2.0, 0.0, -1.25),
+            ),
+        ];
+
+        let rays = [
+            Ray::new(Point::new(0.0, 3.0, 0.2), Vector3::new(0.0, -1.0, 0.0)),
+            Ray::new(Point::new(0.0, -3.0, 0.2), Vector3::new(0.0, -1.0, 0.0)),
+            Ray::new(Point::new(0.0, 0.5, 0.2), Vector3::new(0.0, 1.0, 0.0)),
+            Ray::new(Point::new(3.0, 3.0, 3.0), Vector3::new(0.0, -1.0, 0.0)),
+            Ray::new(Point::new(0.0, 2.0, -6.0), Vector3::new(0.0, 0.0, 1.0)),
+            Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0)),
+        ];
+
+        for triangle in &triangles {
+            for ray in &rays {
+                assert_eq!(ray.intersects(triangle), ray.collides(triangle).is_some());
+                assert_eq!(ray.intersects(triangle), ray.is_colliding(triangle));
+
+                let segment = LineSegment::new(ray.origin, ray.origin + ray.direction * 10.0);
+                assert_eq!(
+                    segment.intersects(triangle),
+                    segment.collides(triangle).is_some()
+                );
+            }
+        }
+
+        let ray = Ray::new(Point::new(0.0, 3.0, 0.2), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            ray.collides(&triangles[1]),
+            Some(Contact::new(
+                Point::new(0.0, 1.0, 0.2),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.0
+            ))
+        );
+    }
+
+    #[test]
+    fn test_plane_ray_collision() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+
+        // parallel
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.collides(&plane), None);
+
+        // in front
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(ray.collides(&plane), None);
+
+        // behind
+        let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(ray.collides(&plane), None);
+
+        // straight through
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            ray.collides(&plane),
+            Some(Contact::new(
+                Point::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.0
+            ))
+        );
+
+        // diagonally through from behind, so the normal faces the other way
+        let ray = Ray::new(
+            Point::new(-0.5, 0.0, 0.0),
+            Vector3::new(0.5, 1.0, 0.0).normalized(),
+        );
+        assert!(ray.collides(&plane).unwrap().nearly_equals(&Contact::new(
+            Point::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            0.0
+        )));
+    }
+
+    #[test]
+    fn test_plane_segment_collision() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+
+        // parallel
+        let segment = LineSegment::new(Point::new(0.0, 2.0, 0.0), Point::new(0.0, 2.0, 1.0));
+        assert_eq!(segment.collides(&plane), None);
+
+        // entirely in front
+        let segment = LineSegment::new(Point::new(0.0, 3.0, 0.0), Point::new(0.0, 2.0, 0.0));
+        assert_eq!(segment.collides(&plane), None);
+
+        // straight through
+        let segment = LineSegment::new(Point::new(0.0, 3.0, 0.0), Point::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            segment.collides(&plane),
+            Some(Contact::new(
+                Point::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.0
+            ))
+        );
+
+        // straight through from behind
+        let segment = LineSegment::new(Point::new(2.0, 0.0, 0.0), Point::new(2.0, 4.0, 0.0));
+        assert_eq!(
+            segment.collides(&plane),
+            Some(Contact::new(
+                Point::new(2.0, 1.0, 0.0),
+                Vector3::new(0.0, -1.0, 0.0),
+                0.0
+            ))
+        );
+    }
+
+    #[test]
+    fn test_sphere_plane_collision() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+
+        let sphere = Sphere::new(Point::new(2.0, 1.5, 0.0), 1.0);
+        let contact = sphere.collides(&plane).unwrap();
+        assert_eq!(
+            contact,
+            Contact::with_points(
+                Point::new(2.0, 0.5, 0.0),
+                Point::new(2.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.5
+            )
+        );
+
+        // the other way around, the normal points back towards the plane
+        let reverse = plane.collides(&sphere).unwrap();
+        assert_eq!(
+            reverse,
+            Contact::with_points(
+                Point::new(2.0, 1.0, 0.0),
+                Point::new(2.0, 0.5, 0.0),
+                Vector3::new(0.0, -1.0, 0.0),
+                0.5
+            )
+        );
+
+        // behind the plane
+        let sphere = Sphere::new(Point::new(2.0, 0.0, 0.0), 2.0);
+        let contact = sphere.collides(&plane).unwrap();
+        let reverse = plane.collides(&sphere).unwrap();
+        assert_eq!(contact.normal, Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(reverse.normal, -contact.normal);
+        assert_eq!(contact.overlap, 1.0);
+        assert_eq!(reverse.overlap, contact.overlap);
+
+        let sphere = Sphere::new(Point::new(2.0, 3.0, 0.0), 1.0);
+        assert_eq!(sphere.collides(&plane), None);
+        assert_eq!(plane.collides(&sphere), None);
+    }
+
+    #[test]
+    fn test_sphere_aabb_collision() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+        // resting against the top face
+        let sphere = Sphere::new(Point::new(0.5, 1.75, 0.0), 1.0);
+        assert_eq!(
+            sphere.collides(&aabb),
+            Some(Contact::with_points(
+                Point::new(0.5, 0.75, 0.0),
+                Point::new(0.5, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+                0.25
+            ))
+        );
+
+        // the box sees the same contact from the other side
+        let contact = aabb.collides(&sphere).unwrap();
+        assert_eq!(contact.point_a, Point::new(0.5, 1.0, 0.0));
+        assert_eq!(contact.normal, Vector3::new(0.0, -1.0, 0.0));
+
+        // touching a corner
+        let corner = Point::new(1.0, 1.0, 1.0);
+        let direction = Vector3::new(1.0, 1.0, 1.0).normalized();
+        let sphere = Sphere::new(corner + direction * 0.5, 1.0);
+        let contact = sphere.collides(&aabb).unwrap();
+        assert_eq!(contact.point_b, corner);
+        assert!(contact.normal.nearly_equals(&direction));
+        assert!((contact.overlap - 0.5).abs() < 1e-6);
+
+        // clear of the corner, though inside the bounds of the inflated box
+        let sphere = Sphere::new(corner + direction * 1.1, 1.0);
+        assert_eq!(sphere.collides(&aabb), None);
+
+        // with the center inside, pushed out along the shallowest axis
+        let sphere = Sphere::new(Point::new(0.25, 0.5, 0.8), 0.5);
+        let contact = sphere.collides(&aabb).unwrap();
+        assert_eq!(contact.normal, Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(contact.point_b, Point::new(0.25, 0.5, 1.0));
+        assert!((contact.overlap - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_triangle_triangle_collision() {
+        let a = Triangle::default();
+
+        // a vertical triangle piercing the default triangle along x = 0, from z = -0.5 to 0
+        let b = Triangle::new(
+            Point::new(0.0, -1.0, -0.5),
+            Point::new(0.0, 1.0, -0.5),
+            Point::new(0.0, 1.0, 0.5),
+        );
+        let contact = a.collides(&b).unwrap();
+        assert!(contact.point.nearly_equals(&Point::new(0.0, 0.0, -0.25)));
+        assert!(a.distance(&contact.point) < 1e-6);
+        assert!(b.distance(&contact.point) < 1e-6);
+        assert!((contact.normal.z.abs() - 1.0).abs() < 1e-6);
+        assert_eq!(contact.overlap, 0.0);
+
+        // the same segment is found from the other triangle
+        let reverse = b.collides(&a).unwrap();
+        assert!(reverse.point.nearly_equals(&contact.point));
+
+        // coplanar and overlapping, so the contact lies within both, facing along the normal
+        let c = a + Vector3::new(0.5, 0.0, 0.0);
+        let contact = a.collides(&c).unwrap();
+        assert_eq!(contact.normal, a.plane().normal);
+        assert!(a.distance(&contact.point) < 1e-6);
+        assert!(c.distance(&contact.point) < 1e-6);
+
+        // clear of one another
+        let d = b + Vector3::new(3.0, 0.0, 0.0);
+        assert_eq!(a.collides(&d), None);
+    }
+
+    #[test]
+    fn test_mtv() {
+        let a = Sphere::new(Point::zero(), 1.0);
+        let b = Sphere::new(Point::new(0.0, 1.5, 0.0), 1.0);
+
+        // moving by the translation separates the spheres
+        let mtv = b.mtv(&a).unwrap();
+        assert_eq!(mtv, Vector3::new(0.0, 0.5, 0.0));
+        let moved = Sphere::new(b.center + mtv, b.radius);
+        assert!(moved.collides(&a).is_none());
+        assert!(!moved.is_colliding(&a));
+        assert_eq!(moved.mtv(&a), None);
+        assert_eq!(a.mtv(&b), Some(-mtv));
+
+        // and works the same for any other pair
+        let triangle = Triangle::default();
+        let sphere = Sphere::new(Point::new(0.0, 0.75, 0.0), 1.0);
+        let mtv = sphere.mtv(&triangle).unwrap();
+        assert_eq!(mtv, Vector3::new(0.0, 0.25, 0.0));
+        let moved = Sphere::new(sphere.center + mtv, sphere.radius);
+        assert!(moved.collides(&triangle).is_none());
+
+        let c = Sphere::new(Point::new(0.0, 3.0, 0.0), 1.0);
+        assert_eq!(c.mtv(&a), None);
+    }
 }
@@ -0,0 +1,91 @@
+use mini_math::Point;
+
+use crate::{Aabb, Capsule, Ellipsoid, Sphere};
+
+/// Trait for testing whether a point lies inside a solid shape
+///
+/// Points exactly on the boundary count as inside.
+pub trait Contains {
+    /// Whether the given point lies inside the shape
+    fn contains(&self, p: &Point) -> bool;
+}
+
+impl Contains for Sphere {
+    fn contains(&self, p: &Point) -> bool {
+        (*p - self.center).magnitude_squared() <= self.radius * self.radius
+    }
+}
+
+impl Contains for Capsule {
+    fn contains(&self, p: &Point) -> bool {
+        self.signed_distance(p) <= 0.0
+    }
+}
+
+impl Contains for Aabb {
+    fn contains(&self, p: &Point) -> bool {
+        Aabb::contains(self, p)
+    }
+}
+
+impl Contains for Ellipsoid {
+    fn contains(&self, p: &Point) -> bool {
+        Ellipsoid::contains(self, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::Vector3;
+
+    fn check<S: Contains>(shape: &S, inside: Point, outside: Point) {
+        assert!(shape.contains(&inside));
+        assert!(!shape.contains(&outside));
+    }
+
+    #[test]
+    fn test_sphere() {
+        let sphere = Sphere::new(Point::new(1.0, 0.0, 0.0), 2.0);
+        check(
+            &sphere,
+            Point::new(2.99, 0.0, 0.0),
+            Point::new(3.01, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn test_capsule() {
+        let capsule = Capsule::new(Point::new(0.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0), 0.5);
+        check(
+            &capsule,
+            Point::new(0.49, 0.0, 0.0),
+            Point::new(0.51, 0.0, 0.0),
+        );
+        check(
+            &capsule,
+            Point::new(0.0, 1.49, 0.0),
+            Point::new(0.0, 1.51, 0.0),
+        );
+    }
+
+    #[test]
+    fn test_aabb() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+        check(
+            &aabb,
+            Point::new(0.0, 0.99, 0.0),
+            Point::new(0.0, 1.01, 0.0),
+        );
+    }
+
+    #[test]
+    fn test_ellipsoid() {
+        let ellipsoid = Ellipsoid::new(Point::zero(), Vector3::new(2.0, 1.0, 0.5));
+        check(
+            &ellipsoid,
+            Point::new(0.0, 0.0, 0.49),
+            Point::new(0.0, 0.0, 0.51),
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use mini_math::{Point, Vector3};
+
+use crate::{check_finite, scale_of, Plane, ShapeError, ANGULAR_EPSILON, LINEAR_EPSILON};
+
+/// A flat convex polygon
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexPolygon {
+    /// The vertices of the polygon, in order around its boundary
+    pub vertices: Vec<Point>,
+    /// The normal of the plane containing the polygon
+    pub normal: Vector3,
+}
+
+impl ConvexPolygon {
+    /// Construct a polygon from its vertices and normal, which are assumed to be planar and convex
+    pub fn new(vertices: Vec<Point>, normal: Vector3) -> Self {
+        Self { vertices, normal }
+    }
+
+    /// Construct a polygon from its vertices, calculating the normal with the same winding as a
+    /// `Triangle`.
+    ///
+    /// Fails if any vertex is not finite, there are fewer than three vertices, the vertices
+    /// enclose no area, or they don't all lie within the same plane, to within `LINEAR_EPSILON`
+    /// relative to their coordinates.
+    pub fn try_new(vertices: Vec<Point>) -> Result<Self, ShapeError> {
+        for p in &vertices {
+            check_finite(&[p.x, p.y, p.z])?;
+        }
+        if vertices.len() < 3 {
+            return Err(ShapeError::TooFewVertices);
+        }
+
+        // Newell's method, which is robust to collinear runs of vertices, taken relative to the
+        // first vertex to keep precision away from the origin
+        let origin = vertices[0];
+        let mut normal = Vector3::zero();
+        let mut longest_edge_squared = 0.0f32;
+        for (i, p) in vertices.iter().enumerate() {
+            let q = vertices[(i + 1) % vertices.len()];
+            normal -= (*p - origin).cross(q - origin);
+            longest_edge_squared = longest_edge_squared.max((q - *p).magnitude_squared());
+        }
+        let magnitude = normal.magnitude();
+        if magnitude <= ANGULAR_EPSILON * longest_edge_squared {
+            return Err(ShapeError::DegenerateNormal);
+        }
+
+        let polygon = Self::new(vertices, normal / magnitude);
+        if !polygon.is_planar(LINEAR_EPSILON * scale_of(&polygon.vertices)) {
+            return Err(ShapeError::NonPlanar);
+        }
+        Ok(polygon)
+    }
+
+    /// The plane containing the polygon, which passes through the origin if there are no vertices
+    pub fn plane(&self) -> Plane {
+        let origin = self.vertices.first().copied().unwrap_or_else(Point::zero);
+        Plane::from_point_and_normal(origin, self.normal)
+    }
+
+    /// Test if a coplanar point is inside the polygon, which is never the case for fewer than
+    /// three vertices
+    pub fn contains_coplanar(&self, p: Point) -> bool {
+        if self.vertices.len() < 3 {
+            return false;
+        }
+
+        self.vertices.iter().enumerate().all(|(i, a)| {
+            let b = self.vertices[(i + 1) % self.vertices.len()];
+            // reject if the point is outside of this edge
+            self.normal.dot((b - *a).cross(p - *a)) <= 0.0
+        })
+    }
+
+    /// Whether the polygon is convex, with every turn around its boundary in the same direction
+    /// about the normal and the boundary winding around exactly once.
+    ///
+    /// Collinear runs of vertices are allowed, but a polygon with fewer than three vertices or
+    /// no turns at all is not convex.
+    pub fn is_convex(&self) -> bool {
+        let n = self.vertices.len();
+        if n < 3 {
+            return false;
+        }
+
+        let mut sign = 0.0f32;
+        let mut winding = 0.0f32;
+        for i in 0..n {
+            let a = self.vertices[i];
+            let b = self.vertices[(i + 1) % n];
+            let c = self.vertices[(i + 2) % n];
+            let (e1, e2) = (b - a, c - b);
+
+            let turn = self.normal.dot(e1.cross(e2));
+            if turn.abs() <= ANGULAR_EPSILON * e1.magnitude() * e2.magnitude() {
+                continue;
+            }
+            if sign != 0.0 && turn.signum() != sign {
+                return false;
+            }
+            sign = turn.signum();
+            winding += turn.atan2(e1.dot(e2));
+        }
+
+        // a star polygon turns the same way at every vertex, but winds around more than once
+        sign != 0.0 && (winding.abs() - std::f32::consts::TAU).abs() < 1e-3
+    }
+
+    /// Whether every vertex lies within `epsilon` of the plane of the polygon
+    pub fn is_planar(&self, epsilon: f32) -> bool {
+        let plane = self.plane();
+        self.vertices
+            .iter()
+            .all(|p| plane.signed_distance(p).abs() <= epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Ray, Raycast, Triangle};
+
+    fn pentagon() -> Vec<Point> {
+        (0..5)
+            .map(|i| {
+                let theta = std::f32::consts::TAU * i as f32 / 5.0;
+                Point::new(theta.cos(), 0.0, theta.sin())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_try_new() {
+        let polygon = ConvexPolygon::try_new(pentagon()).unwrap();
+        assert!((polygon.normal - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+
+        // the same winding as a triangle
+        let triangle = Triangle::default();
+        let polygon = ConvexPolygon::try_new(triangle.vertices().to_vec()).unwrap();
+        assert!((polygon.normal - Plane::from(&triangle).normal).magnitude() < 1e-6);
+
+        let mut vertices = pentagon();
+        vertices[2].y = 0.1;
+        assert_eq!(ConvexPolygon::try_new(vertices), Err(ShapeError::NonPlanar));
+
+        let vertices = vec![Point::zero(), Point::new(1.0, 0.0, 0.0)];
+        assert_eq!(
+            ConvexPolygon::try_new(vertices),
+            Err(ShapeError::TooFewVertices)
+        );
+
+        let vertices = vec![
+            Point::zero(),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+        ];
+        assert_eq!(
+            ConvexPolygon::try_new(vertices),
+            Err(ShapeError::DegenerateNormal)
+        );
+
+        let mut vertices = pentagon();
+        vertices[1].x = f32::NAN;
+        assert_eq!(ConvexPolygon::try_new(vertices), Err(ShapeError::NonFinite));
+
+        // a tilted square far from the origin is planar to within the precision of its coordinates
+        let vertices = vec![
+            Point::new(1000.0, 1000.0, 1000.0),
+            Point::new(1500.0, 1000.0, 1000.0),
+            Point::new(1500.0, 1300.0, 1400.0),
+            Point::new(1000.0, 1300.0, 1400.0),
+        ];
+        let polygon = ConvexPolygon::try_new(vertices).unwrap();
+        assert!((polygon.normal - Vector3::new(0.0, 0.8, -0.6)).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty() {
+        let polygon = ConvexPolygon::new(vec![], Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            polygon.plane(),
+            Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0)
+        );
+        assert!(!polygon.contains_coplanar(Point::zero()));
+
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(polygon.raycast(&ray), None);
+    }
+
+    #[test]
+    fn test_contains_coplanar() {
+        let polygon = ConvexPolygon::try_new(pentagon()).unwrap();
+
+        // just either side of the middle of the first edge
+        let theta = std::f32::consts::PI / 5.0;
+        let edge = theta.cos();
+        let direction = Vector3::new(theta.cos(), 0.0, theta.sin());
+
+        assert!(polygon.contains_coplanar(Point::zero()));
+        assert!(polygon.contains_coplanar(Point::zero() + direction * (edge - 0.01)));
+        assert!(!polygon.contains_coplanar(Point::zero() + direction * (edge + 0.01)));
+    }
+
+    #[test]
+    fn test_is_convex() {
+        let polygon = ConvexPolygon::try_new(pentagon()).unwrap();
+        assert!(polygon.is_convex());
+
+        let arrow = ConvexPolygon::new(
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(2.0, 0.0, 1.0),
+                Point::new(0.0, 0.0, 2.0),
+                Point::new(0.5, 0.0, 1.0),
+            ],
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert!(!arrow.is_convex());
+
+        // a pentagram turns the same way at every vertex, but winds around twice
+        let pentagon = pentagon();
+        let pentagram = ConvexPolygon::new(
+            (0..5).map(|i| pentagon[(i * 2) % 5]).collect(),
+            polygon.normal,
+        );
+        assert!(!pentagram.is_convex());
+    }
+
+    #[test]
+    fn test_is_planar() {
+        let polygon = ConvexPolygon::try_new(pentagon()).unwrap();
+        assert!(polygon.is_planar(1e-4));
+
+        let quad = ConvexPolygon::new(
+            vec![
+                Point::new(0.0, 0.0, 0.0),
+                Point::new(1.0, 0.0, 0.0),
+                Point::new(1.0, 0.01, 1.0),
+                Point::new(0.0, 0.0, 1.0),
+            ],
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert!(!quad.is_planar(1e-3));
+        assert!(quad.is_planar(0.1));
+    }
+}
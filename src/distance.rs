@@ -1,13 +1,32 @@
 use mini_math::{Point, Vector3};
 
-use crate::{Capsule, ClosestPoint, Line, LineSegment, Plane, Ray, Sphere};
+use crate::{
+    nearly_parallel, Aabb, Capsule, ClosestPoint, Line, LineSegment, Plane, Ray, Shape, Sphere,
+    Triangle, ANGULAR_EPSILON,
+};
 
 /// Trait for finding the distance between two objects
+///
+/// The distance is never negative. Solid shapes (spheres, capsules and boxes) count as filled,
+/// so anything that touches or lies inside one is at zero distance from it, rather than at its
+/// distance from the surface. A plane bounds no volume, so it is the same distance from points
+/// either side of it. Where the sign is meaningful, spheres, capsules and planes provide an
+/// inherent `signed_distance` to a point, which is negative inside the solid or behind the
+/// plane, along with [`Line::signed_distance_to_sphere`] and [`Aabb::signed_distance_to_sphere`].
 pub trait Distance<Other> {
     /// The distance between two objects
     fn distance(&self, other: &Other) -> f32;
 }
 
+/// Trait for finding the squared distance between two objects, which avoids a square root where possible
+///
+/// Like the distance, this is never negative. Spheres and planes provide an inherent
+/// `signed_distance_squared` to a point, which keeps the sign of their signed distance.
+pub trait DistanceSquared<Other> {
+    /// The squared distance between two objects
+    fn distance_squared(&self, other: &Other) -> f32;
+}
+
 impl Distance<Point> for Line {
     fn distance(&self, p: &Point) -> f32 {
         let cross = self.direction.cross(*p - self.point);
@@ -23,17 +42,54 @@ impl Distance<Line> for Line {
         let e = line.direction.dot(w);
         let d_p = 1.0 - b * b;
 
-        let (sc, tc) = if d_p < std::f32::EPSILON {
-            (0.0, if b > 1.0 { d / b } else { e })
-        } else {
-            ((b * e - d) / d_p, (e - b * d) / d_p)
-        };
+        // parallel and anti-parallel lines are a constant distance apart, where the cross product
+        // measures small angles far more precisely than 1 - b²
+        let sine_squared = self.direction.cross(line.direction).magnitude_squared();
+        if sine_squared <= ANGULAR_EPSILON * ANGULAR_EPSILON {
+            return self.distance(&line.point);
+        }
+
+        let sc = (b * e - d) / d_p;
+        let tc = (e - b * d) / d_p;
 
         let p = w + (self.direction * sc) - (line.direction * tc);
         p.magnitude()
     }
 }
 
+impl Distance<Plane> for Line {
+    fn distance(&self, plane: &Plane) -> f32 {
+        // any line that isn't parallel to the plane crosses it somewhere
+        if nearly_parallel(
+            plane.normal.dot(self.direction),
+            self.direction,
+            ANGULAR_EPSILON,
+        ) {
+            plane.distance(&self.point)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Distance<Line> for Plane {
+    fn distance(&self, line: &Line) -> f32 {
+        line.distance(self)
+    }
+}
+
+impl Distance<Sphere> for Line {
+    fn distance(&self, sphere: &Sphere) -> f32 {
+        self.signed_distance_to_sphere(sphere).max(0.0)
+    }
+}
+
+impl Distance<Line> for Sphere {
+    fn distance(&self, line: &Line) -> f32 {
+        line.distance(self)
+    }
+}
+
 impl Distance<Point> for LineSegment {
     fn distance(&self, p: &Point) -> f32 {
         let q = self.closest_point(p);
@@ -50,7 +106,9 @@ impl Distance<Line> for LineSegment {
 
 impl Distance<LineSegment> for LineSegment {
     fn distance(&self, l: &LineSegment) -> f32 {
-        self.distance(&l.closest_point(self))
+        // clamp both parameters jointly, which handles parallel and degenerate segments
+        let (s, t) = self.closest_parameters(l);
+        (l.point_at(t) - self.point_at(s)).magnitude()
     }
 }
 
@@ -87,25 +145,45 @@ impl Distance<Ray> for LineSegment {
 
 impl Distance<Point> for Plane {
     fn distance(&self, p: &Point) -> f32 {
-        self.normal.dot(Vector3::from(*p)) - self.d
+        self.signed_distance(p).abs()
     }
 }
 
 impl Distance<Point> for Sphere {
     fn distance(&self, p: &Point) -> f32 {
-        (*p - self.center).magnitude() - self.radius
+        self.signed_distance(p).max(0.0)
+    }
+}
+
+impl Distance<Point> for Triangle {
+    fn distance(&self, p: &Point) -> f32 {
+        (*p - self.closest_point(p)).magnitude()
+    }
+}
+
+impl Distance<Point> for Shape {
+    fn distance(&self, p: &Point) -> f32 {
+        match self {
+            Shape::Sphere(s) => s.distance(p),
+            Shape::Capsule(c) => c.distance(p),
+            Shape::Triangle(t) => t.distance(p),
+            Shape::Plane(plane) => plane.distance(p),
+            Shape::LineSegment(l) => l.distance(p),
+            Shape::Line(l) => l.distance(p),
+            Shape::Ray(r) => r.distance(p),
+        }
     }
 }
 
 impl Distance<Point> for Capsule {
     fn distance(&self, p: &Point) -> f32 {
-        self.axis.distance(p) - self.radius
+        self.signed_distance(p).max(0.0)
     }
 }
 
 impl Distance<Line> for Capsule {
     fn distance(&self, other: &Line) -> f32 {
-        self.axis.distance(other) - self.radius
+        (self.axis.distance(other) - self.radius).max(0.0)
     }
 }
 
@@ -117,7 +195,7 @@ impl Distance<Capsule> for Line {
 
 impl Distance<Ray> for Capsule {
     fn distance(&self, other: &Ray) -> f32 {
-        self.axis.distance(other) - self.radius
+        (self.axis.distance(other) - self.radius).max(0.0)
     }
 }
 
@@ -129,7 +207,7 @@ impl Distance<Capsule> for Ray {
 
 impl Distance<Sphere> for Capsule {
     fn distance(&self, other: &Sphere) -> f32 {
-        self.axis.distance(&other.center) - self.radius - other.radius
+        (self.axis.distance(&other.center) - self.radius - other.radius).max(0.0)
     }
 }
 
@@ -141,7 +219,67 @@ impl Distance<Capsule> for Sphere {
 
 impl Distance<Capsule> for Capsule {
     fn distance(&self, other: &Capsule) -> f32 {
-        self.axis.distance(&other.axis) - self.radius - other.radius
+        (self.axis.distance(&other.axis) - self.radius - other.radius).max(0.0)
+    }
+}
+
+impl Distance<Point> for Aabb {
+    fn distance(&self, p: &Point) -> f32 {
+        (*p - self.closest_point(p)).magnitude()
+    }
+}
+
+impl Distance<Sphere> for Aabb {
+    fn distance(&self, sphere: &Sphere) -> f32 {
+        self.signed_distance_to_sphere(sphere).max(0.0)
+    }
+}
+
+impl Distance<Aabb> for Sphere {
+    fn distance(&self, aabb: &Aabb) -> f32 {
+        aabb.distance(self)
+    }
+}
+
+impl Distance<Aabb> for Aabb {
+    fn distance(&self, other: &Aabb) -> f32 {
+        // the gap between the boxes along each axis, which is zero where they overlap
+        let gap = (other.min - self.max)
+            .max(self.min - other.max)
+            .max(Vector3::zero());
+        gap.magnitude()
+    }
+}
+
+impl DistanceSquared<Point> for Line {
+    fn distance_squared(&self, p: &Point) -> f32 {
+        self.direction.cross(*p - self.point).magnitude_squared()
+    }
+}
+
+impl DistanceSquared<Point> for LineSegment {
+    fn distance_squared(&self, p: &Point) -> f32 {
+        (*p - self.closest_point(p)).magnitude_squared()
+    }
+}
+
+impl DistanceSquared<Point> for Ray {
+    fn distance_squared(&self, p: &Point) -> f32 {
+        (*p - self.closest_point(p)).magnitude_squared()
+    }
+}
+
+impl DistanceSquared<Point> for Plane {
+    fn distance_squared(&self, p: &Point) -> f32 {
+        let d = self.signed_distance(p);
+        d * d
+    }
+}
+
+impl DistanceSquared<Point> for Sphere {
+    fn distance_squared(&self, p: &Point) -> f32 {
+        let d = self.distance(p);
+        d * d
     }
 }
 
@@ -174,6 +312,70 @@ mod tests {
         assert_eq!(line.distance(&l), 5.0);
     }
 
+    #[test]
+    fn test_line_line_parallel() {
+        let line = Line::from_points(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0));
+
+        // same direction
+        let l = Line::new(Point::new(3.0, 4.0, 7.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(line.distance(&l), 5.0);
+        assert_eq!(l.distance(&line), 5.0);
+
+        // opposite direction
+        let l = Line::new(Point::new(3.0, 4.0, -7.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(line.distance(&l), 5.0);
+        assert_eq!(l.distance(&line), 5.0);
+
+        // coincident
+        let l = Line::new(Point::new(0.0, 0.0, 3.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(line.distance(&l), 0.0);
+    }
+
+    #[test]
+    fn test_line_plane() {
+        let plane = Plane::from_points(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // parallel above the plane
+        let line = Line::new(Point::new(0.0, 3.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(line.distance(&plane), 3.0);
+        assert_eq!(plane.distance(&line), 3.0);
+
+        // parallel below the plane
+        let line = Line::new(Point::new(0.0, -3.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(line.distance(&plane), 3.0);
+        assert_eq!(plane.signed_distance(&line.point), -3.0);
+
+        // crossing the plane
+        let line = Line::from_points(Point::new(0.0, 3.0, 0.0), Point::new(1.0, 4.0, 0.0));
+        assert_eq!(line.distance(&plane), 0.0);
+        assert_eq!(plane.distance(&line), 0.0);
+    }
+
+    #[test]
+    fn test_line_sphere() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, 0.0), 5.0);
+
+        // tangent
+        let line = Line::new(Point::new(0.0, 5.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(line.distance(&sphere), 0.0);
+        assert_eq!(sphere.distance(&line), 0.0);
+        assert_eq!(line.signed_distance_to_sphere(&sphere), 0.0);
+
+        // clear of the sphere
+        let line = Line::new(Point::new(0.0, 8.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(line.distance(&sphere), 3.0);
+        assert_eq!(line.signed_distance_to_sphere(&sphere), 3.0);
+
+        // through the center
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(line.distance(&sphere), 0.0);
+        assert_eq!(line.signed_distance_to_sphere(&sphere), -5.0);
+    }
+
     #[test]
     fn test_ray_point() {
         let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
@@ -289,6 +491,24 @@ mod tests {
         assert_eq!(sphere.distance(&p), 10.0);
     }
 
+    #[test]
+    fn test_triangle_point() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        let p = Point::new(0.0, 2.0, 0.0);
+        assert_eq!(triangle.distance(&p), 2.0);
+
+        let p = Point::new(0.0, -2.0, 0.0);
+        assert_eq!(triangle.distance(&p), 2.0);
+
+        let p = Point::new(0.0, 0.0, 4.0);
+        assert_eq!(triangle.distance(&p), 3.0);
+    }
+
     #[test]
     fn test_capsule_point() {
         let cap = Capsule::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 5.0, 0.0), 1.0);
@@ -312,6 +532,159 @@ mod tests {
         assert_eq!(plane.distance(&p), 1.0);
 
         let p = Point::new(-2.0, -1.0, -3.0);
-        assert_eq!(plane.distance(&p), -1.0);
+        assert_eq!(plane.distance(&p), 1.0);
+        assert_eq!(plane.signed_distance(&p), -1.0);
+    }
+
+    #[test]
+    fn test_distance_squared() {
+        let points = [
+            Point::new(0.0, 0.0, -5.0),
+            Point::new(0.0, 5.0, 25.0),
+            Point::new(0.0, 3.0, -4.0),
+        ];
+
+        let line = Line::from_points(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0));
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0));
+        let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+
+        for p in &points {
+            assert!((line.distance_squared(p) - line.distance(p).powi(2)).abs() < 1e-3);
+            assert!((segment.distance_squared(p) - segment.distance(p).powi(2)).abs() < 1e-3);
+            assert!((ray.distance_squared(p) - ray.distance(p).powi(2)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_signed_distance_squared() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, 0.0), 5.0);
+        assert_eq!(
+            sphere.signed_distance_squared(&Point::new(0.0, 0.0, 15.0)),
+            100.0
+        );
+        assert_eq!(
+            sphere.signed_distance_squared(&Point::new(0.0, 0.0, 2.0)),
+            -9.0
+        );
+
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0);
+        assert_eq!(
+            plane.signed_distance_squared(&Point::new(3.0, 2.0, 2.0)),
+            4.0
+        );
+        assert_eq!(
+            plane.signed_distance_squared(&Point::new(3.0, -2.0, 2.0)),
+            -4.0
+        );
+    }
+
+    #[test]
+    fn test_distance_squared_unsigned() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, 0.0), 5.0);
+        assert_eq!(sphere.distance_squared(&Point::new(0.0, 0.0, 15.0)), 100.0);
+        assert_eq!(sphere.distance_squared(&Point::new(0.0, 0.0, 2.0)), 0.0);
+
+        let plane = Plane::from_points(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+        assert_eq!(plane.distance_squared(&Point::new(3.0, 2.0, 2.0)), 4.0);
+        assert_eq!(plane.distance_squared(&Point::new(3.0, -2.0, 2.0)), 4.0);
+    }
+
+    #[test]
+    fn test_line_segment_line_segment_parallel() {
+        let line = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 10.0));
+
+        // partially overlapping, one unit apart
+        let l = LineSegment::new(Point::new(0.0, 1.0, 5.0), Point::new(0.0, 1.0, 15.0));
+        assert_eq!(line.distance(&l), 1.0);
+        assert_eq!(l.distance(&line), 1.0);
+
+        // partially overlapping and anti-parallel
+        let l = LineSegment::new(Point::new(3.0, 4.0, 15.0), Point::new(3.0, 4.0, 5.0));
+        assert_eq!(line.distance(&l), 5.0);
+        assert_eq!(l.distance(&line), 5.0);
+
+        // one entirely within the span of the other
+        let l = LineSegment::new(Point::new(2.0, 0.0, 2.0), Point::new(2.0, 0.0, 4.0));
+        assert_eq!(line.distance(&l), 2.0);
+        assert_eq!(l.distance(&line), 2.0);
+
+        // collinear and overlapping
+        let l = LineSegment::new(Point::new(0.0, 0.0, -5.0), Point::new(0.0, 0.0, 5.0));
+        assert_eq!(line.distance(&l), 0.0);
+
+        // parallel, but offset along the axis so that only the end points are closest
+        let l = LineSegment::new(Point::new(0.0, 3.0, 14.0), Point::new(0.0, 3.0, 20.0));
+        assert_eq!(line.distance(&l), 5.0);
+        assert_eq!(l.distance(&line), 5.0);
+        // degenerate segments
+        let l = LineSegment::new(Point::new(0.0, 5.0, 5.0), Point::new(0.0, 5.0, 5.0));
+        assert_eq!(line.distance(&l), 5.0);
+        assert_eq!(l.distance(&line), 5.0);
+        assert_eq!(l.distance(&l), 0.0);
+    }
+
+    #[test]
+    fn test_sign_contract() {
+        // distances to lines and segments are never negative, even through them
+        let line = Line::from_points(Point::zero(), Point::new(1.0, 0.0, 0.0));
+        let segment = LineSegment::new(Point::zero(), Point::new(1.0, 0.0, 0.0));
+        for p in [
+            Point::zero(),
+            Point::new(0.5, 0.0, 0.0),
+            Point::new(0.0, -2.0, 0.0),
+            Point::new(-3.0, 1.0, -1.0),
+        ] {
+            assert!(line.distance(&p) >= 0.0);
+            assert!(segment.distance(&p) >= 0.0);
+        }
+
+        // solid shapes go negative inside, while their distance stops at zero
+        let sphere = Sphere::new(Point::zero(), 2.0);
+        assert_eq!(sphere.signed_distance(&Point::new(0.5, 0.0, 0.0)), -1.5);
+        assert_eq!(sphere.distance(&Point::new(0.5, 0.0, 0.0)), 0.0);
+        assert_eq!(sphere.signed_distance(&Point::new(3.0, 0.0, 0.0)), 1.0);
+        let p = Point::new(3.0, 4.0, 0.0);
+        assert_eq!(sphere.signed_distance(&p), sphere.distance(&p));
+
+        let capsule = Capsule::new(Point::zero(), Point::new(0.0, 2.0, 0.0), 1.0);
+        assert_eq!(capsule.signed_distance(&Point::new(0.0, 1.0, 0.0)), -1.0);
+        assert_eq!(capsule.distance(&Point::new(0.0, 1.0, 0.0)), 0.0);
+        assert_eq!(capsule.signed_distance(&Point::new(0.0, 5.0, 0.0)), 2.0);
+
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+        assert_eq!(plane.signed_distance(&Point::zero()), -1.0);
+        assert_eq!(plane.distance(&Point::zero()), 1.0);
+        assert_eq!(plane.signed_distance(&Point::new(0.0, 3.0, 0.0)), 2.0);
+    }
+
+    #[test]
+    fn test_aabb_distances() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+        // beyond a corner, and inside
+        assert_eq!(aabb.distance(&Point::new(3.0, 4.0, -3.0)), 17.0f32.sqrt());
+        assert_eq!(aabb.distance(&Point::new(0.5, 0.0, -0.5)), 0.0);
+
+        // a sphere overlapping the top face, and one clear of it
+        let sphere = Sphere::new(Point::new(0.0, 1.5, 0.0), 1.0);
+        assert_eq!(aabb.signed_distance_to_sphere(&sphere), -0.5);
+        assert_eq!(aabb.distance(&sphere), 0.0);
+        assert_eq!(sphere.distance(&aabb), 0.0);
+        let sphere = Sphere::new(Point::new(0.0, 4.0, 0.0), 1.0);
+        assert_eq!(aabb.distance(&sphere), 2.0);
+        assert_eq!(aabb.signed_distance_to_sphere(&sphere), 2.0);
+        let sphere = Sphere::new(Point::new(0.5, 0.0, 0.0), 1.0);
+        assert_eq!(aabb.signed_distance_to_sphere(&sphere), -1.0);
+
+        // boxes offset diagonally, and overlapping
+        let other = Aabb::new(Point::new(3.0, 4.0, -5.0), Point::new(5.0, 6.0, 5.0));
+        assert_eq!(aabb.distance(&other), 2.0f32.hypot(3.0));
+        assert_eq!(other.distance(&aabb), aabb.distance(&other));
+        let other = Aabb::new(Point::new(0.0, 0.0, 0.0), Point::new(2.0, 2.0, 2.0));
+        assert_eq!(aabb.distance(&other), 0.0);
     }
 }
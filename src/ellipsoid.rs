@@ -0,0 +1,39 @@
+use mini_math::{Point, Vector3};
+
+/// An axis-aligned ellipsoid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid {
+    /// The center of the ellipsoid
+    pub center: Point,
+    /// The radius of the ellipsoid along each axis
+    pub radii: Vector3,
+}
+
+impl Ellipsoid {
+    /// Construct an ellipsoid from a center point and the radius along each axis
+    pub fn new(center: Point, radii: Vector3) -> Self {
+        Self { center, radii }
+    }
+
+    /// Whether the given point lies inside the ellipsoid
+    pub fn contains(&self, p: &Point) -> bool {
+        ((*p - self.center) / self.radii).magnitude_squared() <= 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let ellipsoid = Ellipsoid::new(Point::zero(), Vector3::new(2.0, 1.0, 0.5));
+
+        assert!(ellipsoid.contains(&Point::new(1.9, 0.0, 0.0)));
+        assert!(!ellipsoid.contains(&Point::new(2.1, 0.0, 0.0)));
+        assert!(ellipsoid.contains(&Point::new(0.0, 0.9, 0.0)));
+        assert!(!ellipsoid.contains(&Point::new(0.0, 1.1, 0.0)));
+        assert!(ellipsoid.contains(&Point::new(0.0, 0.0, 0.4)));
+        assert!(!ellipsoid.contains(&Point::new(0.0, 0.0, 0.6)));
+    }
+}
@@ -0,0 +1,36 @@
+use std::fmt;
+
+/// Reasons that a shape could not be constructed from the given inputs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// One of the inputs was NaN or infinite
+    NonFinite,
+    /// The normal was too close to zero length to define a direction
+    DegenerateNormal,
+    /// There were too few vertices to enclose an area
+    TooFewVertices,
+    /// The vertices did not all lie in the same plane
+    NonPlanar,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShapeError::NonFinite => write!(f, "shape inputs must be finite"),
+            ShapeError::DegenerateNormal => write!(f, "normal must not be zero length"),
+            ShapeError::TooFewVertices => write!(f, "polygons need at least three vertices"),
+            ShapeError::NonPlanar => write!(f, "polygon vertices must lie in the same plane"),
+        }
+    }
+}
+
+impl std::error::Error for ShapeError {}
+
+/// Check that all of the given components are finite
+pub(crate) fn check_finite(components: &[f32]) -> Result<(), ShapeError> {
+    if components.iter().all(|c| c.is_finite()) {
+        Ok(())
+    } else {
+        Err(ShapeError::NonFinite)
+    }
+}
@@ -0,0 +1,106 @@
+use mini_math::Point;
+
+use crate::{Aabb, Plane};
+
+/// How much of a shape lies within a volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    /// The shape lies entirely outside the volume
+    Outside,
+    /// The shape lies partly inside the volume
+    Intersecting,
+    /// The shape lies entirely inside the volume
+    Inside,
+}
+
+/// A convex volume bounded by six planes, such as the view volume of a camera.
+///
+/// Shapes are tested against each plane in turn, which is conservative: boxes, spheres and
+/// triangles just outside the frustum near its corners may be reported as intersecting it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// The bounding planes, conventionally left, right, bottom, top, near and far, with their
+    /// normals facing into the frustum
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Construct a frustum from its bounding planes, with the normals facing inwards
+    pub fn new(planes: [Plane; 6]) -> Self {
+        Self { planes }
+    }
+
+    /// Classify how much of a box lies within the frustum
+    pub fn classify_aabb(&self, aabb: &Aabb) -> Containment {
+        let mut result = Containment::Inside;
+
+        for plane in &self.planes {
+            // the corners of the box farthest along the normal, and farthest against it
+            let n = plane.normal;
+            let p_vertex = Point::new(
+                if n.x >= 0.0 { aabb.max.x } else { aabb.min.x },
+                if n.y >= 0.0 { aabb.max.y } else { aabb.min.y },
+                if n.z >= 0.0 { aabb.max.z } else { aabb.min.z },
+            );
+            let n_vertex = Point::new(
+                if n.x >= 0.0 { aabb.min.x } else { aabb.max.x },
+                if n.y >= 0.0 { aabb.min.y } else { aabb.max.y },
+                if n.z >= 0.0 { aabb.min.z } else { aabb.max.z },
+            );
+
+            if plane.signed_distance(&p_vertex) < 0.0 {
+                return Containment::Outside;
+            }
+            if plane.signed_distance(&n_vertex) < 0.0 {
+                result = Containment::Intersecting;
+            }
+        }
+
+        result
+    }
+}
+
+/// A frustum looking down -Z, with a 90° field of view, from z = -1 to z = -10
+#[cfg(test)]
+pub(crate) fn view_frustum() -> Frustum {
+    use mini_math::Vector3;
+
+    let side = |x: f32, y: f32| Plane::new(Vector3::new(x, y, -1.0).normalized(), 0.0);
+    Frustum::new([
+        side(1.0, 0.0),
+        side(-1.0, 0.0),
+        side(0.0, 1.0),
+        side(0.0, -1.0),
+        Plane::new(Vector3::new(0.0, 0.0, -1.0), 1.0),
+        Plane::new(Vector3::new(0.0, 0.0, 1.0), -10.0),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_aabb() {
+        let frustum = view_frustum();
+
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -6.0), Point::new(1.0, 1.0, -4.0));
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Inside);
+
+        // straddling the left plane
+        let aabb = Aabb::new(Point::new(-6.0, -1.0, -6.0), Point::new(-4.0, 1.0, -4.0));
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Intersecting);
+
+        // straddling the far plane
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -11.0), Point::new(1.0, 1.0, -9.0));
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Intersecting);
+
+        // in front of the near plane
+        let aabb = Aabb::new(Point::new(-0.1, -0.1, -0.5), Point::new(0.1, 0.1, 0.5));
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Outside);
+
+        // off to the side
+        let aabb = Aabb::new(Point::new(8.0, -1.0, -6.0), Point::new(10.0, 1.0, -4.0));
+        assert_eq!(frustum.classify_aabb(&aabb), Containment::Outside);
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{Capsule, ClosestPoint, Distance, LineSegment, Plane, Ray, Sphere, Triangle};
+use crate::{
+    nearly_parallel, scale_of, Aabb, Capsule, ClosestPoint, Containment, Distance, Frustum, Line,
+    LineSegment, Plane, Ray, Raycast, SegmentOverlap, Sphere, Triangle, ANGULAR_EPSILON,
+    LINEAR_EPSILON,
+};
 use mini_math::Vector3;
 
 /// Trait for determining whether two shapes intersect with one another
@@ -9,8 +13,9 @@ pub trait Intersection<Rhs> {
 
 impl Intersection<Ray> for Sphere {
     fn intersects(&self, ray: &Ray) -> bool {
-        let p = ray.closest_point(&self.center);
-        self.distance(&p) < 0.0
+        let (a, b, c) = self.ray_quadratic(ray);
+        // either the ray starts inside, or it heads towards the sphere and passes through it
+        c < 0.0 || (b < 0.0 && b * b - a * c > 0.0)
     }
 }
 
@@ -22,7 +27,7 @@ impl Intersection<Sphere> for Ray {
 
 impl Intersection<Capsule> for Ray {
     fn intersects(&self, rhs: &Capsule) -> bool {
-        self.distance(rhs) < 0.0
+        rhs.axis.distance(self) < rhs.radius
     }
 }
 
@@ -46,10 +51,60 @@ impl Intersection<Plane> for Ray {
     }
 }
 
+impl Intersection<Line> for Sphere {
+    fn intersects(&self, line: &Line) -> bool {
+        let m = line.point - self.center;
+        let a = line.direction.dot(line.direction);
+        let b = m.dot(line.direction);
+        let c = m.dot(m) - self.radius * self.radius;
+
+        // a tangent line touches the sphere at a single point, with a discriminant of zero
+        b * b - a * c >= 0.0
+    }
+}
+
+impl Intersection<Sphere> for Line {
+    fn intersects(&self, sphere: &Sphere) -> bool {
+        sphere.intersects(self)
+    }
+}
+
+impl Intersection<Line> for Plane {
+    fn intersects(&self, line: &Line) -> bool {
+        // only a line parallel to the plane can miss it, and only if it doesn't lie within it
+        let parallel = nearly_parallel(
+            self.normal.dot(line.direction),
+            line.direction,
+            ANGULAR_EPSILON,
+        );
+        let tolerance = LINEAR_EPSILON * scale_of(&[line.point]).max(self.d.abs());
+        !parallel || self.distance(&line.point) <= tolerance
+    }
+}
+
+impl Intersection<Plane> for Line {
+    fn intersects(&self, plane: &Plane) -> bool {
+        plane.intersects(self)
+    }
+}
+
+impl Intersection<LineSegment> for Plane {
+    fn intersects(&self, segment: &LineSegment) -> bool {
+        // the end points straddle the plane, or at least one of them lies on it
+        self.signed_distance(&segment.start) * self.signed_distance(&segment.end) <= 0.0
+    }
+}
+
+impl Intersection<Plane> for LineSegment {
+    fn intersects(&self, plane: &Plane) -> bool {
+        plane.intersects(self)
+    }
+}
+
 impl Intersection<LineSegment> for Sphere {
     fn intersects(&self, line: &LineSegment) -> bool {
         let p = line.closest_point(&self.center);
-        self.distance(&p) < 0.0
+        self.signed_distance(&p) < 0.0
     }
 }
 
@@ -61,7 +116,7 @@ impl Intersection<Sphere> for LineSegment {
 
 impl Intersection<Sphere> for Plane {
     fn intersects(&self, sphere: &Sphere) -> bool {
-        self.distance(&sphere.center).abs() <= sphere.radius
+        self.distance(&sphere.center) <= sphere.radius
     }
 }
 
@@ -71,6 +126,99 @@ impl Intersection<Plane> for Sphere {
     }
 }
 
+impl Intersection<Aabb> for Plane {
+    fn intersects(&self, aabb: &Aabb) -> bool {
+        // project the half extents of the box onto the normal of the plane
+        let e = aabb.half_extents();
+        let r = e.x * self.normal.x.abs() + e.y * self.normal.y.abs() + e.z * self.normal.z.abs();
+        self.distance(&aabb.center()) <= r
+    }
+}
+
+impl Intersection<Plane> for Aabb {
+    fn intersects(&self, plane: &Plane) -> bool {
+        plane.intersects(self)
+    }
+}
+
+impl Intersection<LineSegment> for LineSegment {
+    fn intersects(&self, other: &LineSegment) -> bool {
+        self.overlap(other) != SegmentOverlap::None
+    }
+}
+
+impl Intersection<Aabb> for Capsule {
+    fn intersects(&self, aabb: &Aabb) -> bool {
+        let axis = &self.axis;
+        if aabb.contains(&axis.start)
+            || aabb
+                .raycast(&Ray::new(axis.start, axis.end - axis.start))
+                .is_some_and(|hit| hit.t <= 1.0)
+        {
+            return true;
+        }
+
+        // clear of the box, the axis is nearest to it at an end, or to one of its edges
+        let r2 = self.radius * self.radius;
+        [axis.start, axis.end]
+            .iter()
+            .any(|p| (aabb.closest_point(p) - *p).magnitude_squared() <= r2)
+            || aabb
+                .edges()
+                .iter()
+                .any(|edge| axis.distance(edge) <= self.radius)
+    }
+}
+
+impl Intersection<Capsule> for Aabb {
+    fn intersects(&self, capsule: &Capsule) -> bool {
+        capsule.intersects(self)
+    }
+}
+
+impl Intersection<Frustum> for Sphere {
+    fn intersects(&self, frustum: &Frustum) -> bool {
+        frustum
+            .planes
+            .iter()
+            .all(|plane| plane.signed_distance(&self.center) >= -self.radius)
+    }
+}
+
+impl Intersection<Sphere> for Frustum {
+    fn intersects(&self, sphere: &Sphere) -> bool {
+        sphere.intersects(self)
+    }
+}
+
+impl Intersection<Frustum> for Aabb {
+    fn intersects(&self, frustum: &Frustum) -> bool {
+        frustum.classify_aabb(self) != Containment::Outside
+    }
+}
+
+impl Intersection<Aabb> for Frustum {
+    fn intersects(&self, aabb: &Aabb) -> bool {
+        aabb.intersects(self)
+    }
+}
+
+impl Intersection<Frustum> for Triangle {
+    fn intersects(&self, frustum: &Frustum) -> bool {
+        frustum.planes.iter().all(|plane| {
+            self.vertices()
+                .iter()
+                .any(|vertex| plane.signed_distance(vertex) >= 0.0)
+        })
+    }
+}
+
+impl Intersection<Triangle> for Frustum {
+    fn intersects(&self, triangle: &Triangle) -> bool {
+        triangle.intersects(self)
+    }
+}
+
 impl Intersection<Sphere> for Sphere {
     fn intersects(&self, sphere: &Sphere) -> bool {
         let combined_radius = self.radius + sphere.radius;
@@ -80,7 +228,7 @@ impl Intersection<Sphere> for Sphere {
 
 impl Intersection<Sphere> for Triangle {
     fn intersects(&self, sphere: &Sphere) -> bool {
-        let plane = Plane::from(self);
+        let plane = self.plane();
 
         let p = plane.closest_point(&sphere.center);
         let distance_from_plane_squared = (p - sphere.center).magnitude_squared();
@@ -89,12 +237,15 @@ impl Intersection<Sphere> for Triangle {
             return false;
         }
 
-        let radius_on_plane = (sphere.radius * sphere.radius - distance_from_plane_squared).sqrt();
         let coordinates = self.barycentric_coordinates(p);
+        if coordinates.x >= 0.0 && coordinates.y >= 0.0 && coordinates.z >= 0.0 {
+            return true;
+        }
 
-        coordinates.x > -radius_on_plane
-            && coordinates.y > -radius_on_plane
-            && coordinates.z > -radius_on_plane
+        // otherwise the sphere can only reach the triangle across one of its edges
+        self.edges()
+            .iter()
+            .any(|edge| edge.distance(&sphere.center) <= sphere.radius)
     }
 }
 
@@ -106,11 +257,11 @@ impl Intersection<Triangle> for Sphere {
 
 impl Intersection<Ray> for Triangle {
     fn intersects(&self, ray: &Ray) -> bool {
-        let plane = Plane::from(self);
+        let plane = self.plane();
 
         let n_dot_r = plane.normal.dot(ray.direction);
         // early exit if ray parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, ray.direction, ANGULAR_EPSILON) {
             return false;
         }
 
@@ -135,7 +286,7 @@ impl Intersection<Triangle> for Ray {
 
 impl Intersection<LineSegment> for Triangle {
     fn intersects(&self, line: &LineSegment) -> bool {
-        let plane = Plane::from(self);
+        let plane = self.plane();
 
         let mut direction = line.end - line.start;
         let length = direction.magnitude();
@@ -143,7 +294,7 @@ impl Intersection<LineSegment> for Triangle {
 
         let n_dot_r = plane.normal.dot(direction);
         // early exit if line parallel to plane
-        if n_dot_r.abs() < std::f32::EPSILON {
+        if nearly_parallel(n_dot_r, direction, ANGULAR_EPSILON) {
             return false;
         }
 
@@ -166,9 +317,43 @@ impl Intersection<Triangle> for LineSegment {
     }
 }
 
+impl Intersection<Triangle> for Triangle {
+    fn intersects(&self, triangle: &Triangle) -> bool {
+        let plane = self.plane();
+        let other = triangle.plane();
+
+        let parallel = plane.normal.cross(other.normal).magnitude() < ANGULAR_EPSILON;
+        if parallel {
+            // parallel triangles can only meet if they share the same plane
+            let [a, b, c] = self.vertices();
+            let tolerance =
+                LINEAR_EPSILON * scale_of(&[a, b, c, triangle.a, triangle.b, triangle.c]);
+            return plane.distance(&triangle.a) <= tolerance && self.coplanar_overlap(triangle);
+        }
+
+        // otherwise the line along which they meet starts and ends on the edges of the triangles
+        self.edges().iter().any(|edge| triangle.intersects(edge))
+            || triangle.edges().iter().any(|edge| self.intersects(edge))
+    }
+}
+
+impl Intersection<Triangle> for Capsule {
+    fn intersects(&self, triangle: &Triangle) -> bool {
+        let (p, q) = triangle.closest_points_to_segment(&self.axis);
+        (p - q).magnitude_squared() <= self.radius * self.radius
+    }
+}
+
+impl Intersection<Capsule> for Triangle {
+    fn intersects(&self, capsule: &Capsule) -> bool {
+        capsule.intersects(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{frustum::view_frustum, Collision};
     use mini_math::{Point, Vector3};
 
     #[test]
@@ -239,6 +424,34 @@ mod tests {
         assert!(sphere.intersects(&plane));
     }
 
+    #[test]
+    fn test_aabb_plane_intersects() {
+        let plane = Plane::from_point_and_normal(
+            Point::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0).normalized(),
+        );
+
+        // entirely in front
+        let aabb = Aabb::new(Point::new(1.0, 1.0, -1.0), Point::new(2.0, 2.0, 1.0));
+        assert!(!plane.intersects(&aabb));
+        assert!(!aabb.intersects(&plane));
+
+        // entirely behind
+        let aabb = Aabb::new(Point::new(-2.0, -2.0, -1.0), Point::new(-1.0, -1.0, 1.0));
+        assert!(!plane.intersects(&aabb));
+        assert!(!aabb.intersects(&plane));
+
+        // straddling the plane, although the center is in front
+        let aabb = Aabb::new(Point::new(-0.5, 0.0, -1.0), Point::new(1.0, 0.5, 1.0));
+        assert!(plane.intersects(&aabb));
+        assert!(aabb.intersects(&plane));
+
+        // only the corner crosses the plane
+        let aabb = Aabb::new(Point::new(-0.1, -0.1, -1.0), Point::new(2.0, 2.0, 1.0));
+        assert!(plane.intersects(&aabb));
+        assert!(aabb.intersects(&plane));
+    }
+
     #[test]
     fn test_sphere_sphere_intersects() {
         let sphere1 = Sphere::new(Point::new(10.0, 0.0, 0.0), 5.0);
@@ -377,4 +590,271 @@ mod tests {
         let line = LineSegment::new(Point::new(-0.5, -2.0, 0.0), Point::new(0.5, 2.0, 0.0));
         assert!(triangle.intersects(&line));
     }
+
+    #[test]
+    fn test_triangle_capsule_intersects() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // resting on the face
+        let capsule = Capsule::new(Point::new(-0.5, 0.5, 0.0), Point::new(0.5, 0.5, 0.0), 1.0);
+        assert!(triangle.intersects(&capsule));
+        assert!(capsule.intersects(&triangle));
+
+        // hovering above the face
+        let capsule = Capsule::new(Point::new(-0.5, 1.5, 0.0), Point::new(0.5, 1.5, 0.0), 1.0);
+        assert!(!triangle.intersects(&capsule));
+        assert!(!capsule.intersects(&triangle));
+
+        // hanging over an edge
+        let capsule = Capsule::new(Point::new(0.0, 0.5, -1.5), Point::new(0.0, 2.0, -3.0), 1.0);
+        assert!(triangle.intersects(&capsule));
+        assert!(capsule.intersects(&triangle));
+
+        // poking a vertex
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 1.5), Point::new(0.0, 0.0, 3.0), 1.0);
+        assert!(triangle.intersects(&capsule));
+        assert!(capsule.intersects(&triangle));
+
+        // clear of the vertex
+        let capsule = Capsule::new(Point::new(0.0, 0.0, 2.5), Point::new(0.0, 0.0, 3.0), 1.0);
+        assert!(!triangle.intersects(&capsule));
+        assert!(!capsule.intersects(&triangle));
+
+        // piercing the face
+        let capsule = Capsule::new(Point::new(0.0, -1.0, 0.0), Point::new(0.0, 1.0, 0.0), 0.1);
+        assert!(triangle.intersects(&capsule));
+        assert!(capsule.intersects(&triangle));
+    }
+
+    #[test]
+    fn test_line_sphere_intersects() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, 0.0), 1.0);
+
+        // passing through the center, with the sphere behind the point on the line
+        let line = Line::new(Point::new(5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert!(sphere.intersects(&line));
+        assert!(line.intersects(&sphere));
+
+        // tangent to the sphere
+        let line = Line::new(Point::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert!(sphere.intersects(&line));
+        assert!(line.intersects(&sphere));
+
+        let line = Line::new(Point::new(0.0, 1.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert!(!sphere.intersects(&line));
+        assert!(!line.intersects(&sphere));
+    }
+
+    #[test]
+    fn test_line_plane_intersects() {
+        let plane = Plane::from_points(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        let line = Line::new(Point::new(0.0, 5.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+        assert!(plane.intersects(&line));
+        assert!(line.intersects(&plane));
+
+        // lying within the plane
+        let line = Line::new(Point::new(3.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 1.0));
+        assert!(plane.intersects(&line));
+        assert!(line.intersects(&plane));
+
+        // parallel to the plane
+        let line = Line::new(Point::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 1.0));
+        assert!(!plane.intersects(&line));
+        assert!(!line.intersects(&plane));
+    }
+
+    #[test]
+    fn test_segment_plane_intersects() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 1.0);
+
+        // straddling
+        let segment = LineSegment::new(Point::new(0.0, 3.0, 0.0), Point::new(1.0, -1.0, 2.0));
+        assert!(plane.intersects(&segment));
+        assert!(segment.intersects(&plane));
+
+        // both in front
+        let segment = LineSegment::new(Point::new(0.0, 3.0, 0.0), Point::new(1.0, 1.5, 2.0));
+        assert!(!plane.intersects(&segment));
+        assert!(!segment.intersects(&plane));
+
+        // both behind
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(1.0, -1.5, 2.0));
+        assert!(!plane.intersects(&segment));
+        assert!(!segment.intersects(&plane));
+
+        // one end point on the plane
+        let segment = LineSegment::new(Point::new(0.0, 3.0, 0.0), Point::new(1.0, 1.0, 2.0));
+        assert!(plane.intersects(&segment));
+        assert!(segment.intersects(&plane));
+    }
+
+    #[test]
+    fn test_triangle_triangle_intersects() {
+        let triangle = Triangle::default();
+
+        // coplanar and overlapping
+        let other = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 2.0),
+        );
+        assert!(triangle.intersects(&other));
+        assert!(other.intersects(&triangle));
+
+        // parallel, but offset from the plane
+        let other = Triangle::new(
+            Point::new(0.0, 0.1, 0.0),
+            Point::new(2.0, 0.1, 0.0),
+            Point::new(1.0, 0.1, 2.0),
+        );
+        assert!(!triangle.intersects(&other));
+        assert!(!other.intersects(&triangle));
+
+        // coplanar and disjoint
+        let other = Triangle::new(
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 2.0),
+        );
+        assert!(!triangle.intersects(&other));
+        assert!(!other.intersects(&triangle));
+
+        // piercing through the middle
+        let other = Triangle::new(
+            Point::new(0.0, -1.0, -0.5),
+            Point::new(0.0, 1.0, -0.5),
+            Point::new(0.0, 0.0, 0.5),
+        );
+        assert!(triangle.intersects(&other));
+        assert!(other.intersects(&triangle));
+
+        // crossing the plane outside the triangle
+        let other = Triangle::new(
+            Point::new(3.0, -1.0, -0.5),
+            Point::new(3.0, 1.0, -0.5),
+            Point::new(3.0, 0.0, 0.5),
+        );
+        assert!(!triangle.intersects(&other));
+        assert!(!other.intersects(&triangle));
+    }
+
+    #[test]
+    fn test_capsule_aabb() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+        // lying on top of the box
+        let capsule = Capsule::new(Point::new(-2.0, 1.5, 0.0), Point::new(2.0, 1.5, 0.0), 0.5);
+        assert!(capsule.intersects(&aabb));
+        assert!(aabb.intersects(&capsule));
+
+        // hovering just above it
+        let capsule = Capsule::new(Point::new(-2.0, 1.6, 0.0), Point::new(2.0, 1.6, 0.0), 0.5);
+        assert!(!capsule.intersects(&aabb));
+
+        // poking a corner end first
+        let corner = Point::new(1.0, 1.0, 1.0);
+        let direction = Vector3::new(1.0, 1.0, 1.0).normalized();
+        let capsule = Capsule::new(corner + direction * 0.4, corner + direction * 3.0, 0.5);
+        assert!(capsule.intersects(&aabb));
+        let capsule = Capsule::new(corner + direction * 0.6, corner + direction * 3.0, 0.5);
+        assert!(!capsule.intersects(&aabb));
+
+        // passing across an edge, with both ends well clear of the box
+        let edge = Vector3::new(1.0, 1.0, 0.0).normalized();
+        let capsule = Capsule::new(
+            Point::new(1.0, 1.0, -5.0) + edge * 0.4,
+            Point::new(1.0, 1.0, 5.0) + edge * 0.4,
+            0.5,
+        );
+        assert!(capsule.intersects(&aabb));
+
+        // passing straight through the box
+        let capsule = Capsule::new(Point::new(-5.0, 0.0, 0.0), Point::new(5.0, 0.0, 0.0), 0.1);
+        assert!(capsule.intersects(&aabb));
+
+        // fully clear
+        let capsule = Capsule::new(Point::new(3.0, 3.0, 3.0), Point::new(5.0, 3.0, 3.0), 1.0);
+        assert!(!capsule.intersects(&aabb));
+    }
+
+    #[test]
+    fn test_frustum() {
+        let frustum = view_frustum();
+
+        // straddling the left plane
+        let sphere = Sphere::new(Point::new(-5.0, 0.0, -5.0), 1.0);
+        assert!(sphere.intersects(&frustum));
+        assert!(frustum.intersects(&sphere));
+        let aabb = Aabb::new(Point::new(-6.0, -1.0, -6.0), Point::new(-4.0, 1.0, -4.0));
+        assert!(aabb.intersects(&frustum));
+        assert!(frustum.intersects(&aabb));
+        let triangle = Triangle::new(
+            Point::new(-8.0, 0.0, -6.0),
+            Point::new(-2.0, 0.0, -6.0),
+            Point::new(-5.0, 0.0, -4.0),
+        );
+        assert!(triangle.intersects(&frustum));
+        assert!(frustum.intersects(&triangle));
+
+        // behind the far plane
+        let sphere = Sphere::new(Point::new(0.0, 0.0, -12.0), 1.0);
+        assert!(!sphere.intersects(&frustum));
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -13.0), Point::new(1.0, 1.0, -11.0));
+        assert!(!aabb.intersects(&frustum));
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -12.0),
+            Point::new(1.0, 0.0, -12.0),
+            Point::new(0.0, 0.0, -11.0),
+        );
+        assert!(!triangle.intersects(&frustum));
+    }
+
+    #[test]
+    fn test_sphere_large_triangle() {
+        let triangle = Triangle::new(
+            Point::new(-100.0, 0.0, -100.0),
+            Point::new(100.0, 0.0, -100.0),
+            Point::new(0.0, 0.0, 100.0),
+        );
+
+        // well outside the a-b edge, though only by a small fraction of the triangle
+        let sphere = Sphere::new(Point::new(0.0, 0.0, -105.0), 1.0);
+        assert!(!triangle.intersects(&sphere));
+        assert!(!sphere.intersects(&triangle));
+        assert!(sphere.collides(&triangle).is_none());
+
+        // and beyond vertex c
+        let sphere = Sphere::new(Point::new(0.0, 0.5, 103.0), 2.0);
+        assert!(!triangle.intersects(&sphere));
+
+        // overlapping the a-b edge from outside, and the face from above
+        let sphere = Sphere::new(Point::new(0.0, 0.5, -100.5), 1.0);
+        assert!(triangle.intersects(&sphere));
+        assert!(sphere.collides(&triangle).is_some());
+        let sphere = Sphere::new(Point::new(10.0, 0.5, 10.0), 1.0);
+        assert!(triangle.intersects(&sphere));
+    }
+
+    #[test]
+    fn test_segment_segment() {
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0));
+
+        let crossing = LineSegment::new(Point::new(1.0, -1.0, 0.0), Point::new(1.0, 1.0, 0.0));
+        assert!(segment.intersects(&crossing));
+        let collinear = LineSegment::new(Point::new(3.0, 0.0, 0.0), Point::new(6.0, 0.0, 0.0));
+        assert!(segment.intersects(&collinear));
+        let skew = LineSegment::new(Point::new(1.0, -1.0, 1.0), Point::new(1.0, 1.0, 1.0));
+        assert!(!segment.intersects(&skew));
+        let shallow = LineSegment::new(Point::new(0.0, -0.001, 0.0), Point::new(4.0, 0.001, 0.0));
+        assert!(segment.intersects(&shallow));
+    }
 }
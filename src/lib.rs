@@ -1,25 +1,91 @@
 //! Collision primitives to accompany the mini-math crate.
 
+use mini_math::{Point, Vector3};
+
+mod aabb;
+mod bounded;
 mod capsule;
+mod closest_pair;
 mod closest_point;
 mod collision;
+mod contains;
+mod convex_polygon;
 mod distance;
+mod ellipsoid;
+mod error;
+mod frustum;
 mod intersection;
 mod line;
 mod line_segment;
 mod plane;
 mod ray;
+mod raycast;
+mod shape;
 mod sphere;
+mod support_map;
 mod triangle;
 
+pub use aabb::*;
+pub use bounded::*;
 pub use capsule::*;
+pub use closest_pair::*;
 pub use closest_point::*;
 pub use collision::*;
+pub use contains::*;
+pub use convex_polygon::*;
 pub use distance::*;
+pub use ellipsoid::*;
+pub use error::*;
+pub use frustum::*;
 pub use intersection::*;
 pub use line::*;
 pub use line_segment::*;
 pub use plane::*;
 pub use ray::*;
+pub use raycast::*;
+pub use shape::*;
 pub use sphere::*;
+pub use support_map::*;
 pub use triangle::*;
+
+/// The tolerance on the sine or cosine of an angle, used to decide whether two directions are
+/// parallel or perpendicular.
+///
+/// Dot products of unit vectors smaller than this are treated as zero, so a ray that is within
+/// this tolerance of parallel to a plane is considered to miss it, rather than hitting it at some
+/// enormous and numerically meaningless distance. Squared quantities, such as the squared length
+/// of the cross product of two unit vectors, are compared against its square.
+pub const ANGULAR_EPSILON: f32 = 1e-5;
+
+/// The tolerance on lengths and distances, relative to the size of the coordinates involved.
+///
+/// The precision of an `f32` coordinate falls as it grows, so lengths are compared against this
+/// multiplied by the largest coordinate in the query, which is a few units in the last place of
+/// that coordinate. A length below that is lost in rounding error, whether the shapes sit at the
+/// origin or thousands of units away from it.
+pub const LINEAR_EPSILON: f32 = 8.0 * f32::EPSILON;
+
+/// Whether a direction runs parallel to a surface, given its dot product `n_dot_d` with the unit
+/// normal of the surface, to within `epsilon` on the cosine of the angle between them. This is
+/// independent of the length of the direction.
+pub(crate) fn nearly_parallel(n_dot_d: f32, direction: Vector3, epsilon: f32) -> bool {
+    n_dot_d.abs() < epsilon * direction.magnitude()
+}
+
+/// The largest magnitude of any coordinate of the given points, to scale `LINEAR_EPSILON` by
+pub(crate) fn scale_of(points: &[Point]) -> f32 {
+    points
+        .iter()
+        .map(|p| p.x.abs().max(p.y.abs()).max(p.z.abs()))
+        .fold(0.0, f32::max)
+}
+
+/// The unit vector along the given vector, or `None` if it is too close to zero length to define
+/// a direction, relative to the scale of the coordinates it was computed from
+pub(crate) fn safe_normalized(v: Vector3, scale: f32) -> Option<Vector3> {
+    if v.magnitude() <= LINEAR_EPSILON * scale {
+        None
+    } else {
+        Some(v.normalized())
+    }
+}
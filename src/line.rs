@@ -1,7 +1,9 @@
 use mini_math::{Point, Vector3};
 
+use crate::{check_finite, safe_normalized, scale_of, Distance, Sphere};
+
 /// An infinite line.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Line {
     /// An arbitrary point on the line.
     pub point: Point,
@@ -16,10 +18,106 @@ impl Line {
     }
 
     /// Construct a line from two points on the line.
+    ///
+    /// The direction will be zero if the points coincide, see `try_from_points`.
     pub fn from_points(start: Point, end: Point) -> Self {
         Self {
             point: start,
             direction: (end - start).normalized(),
         }
     }
+
+    /// Construct a line from two points on the line, or `None` if either is non-finite or they
+    /// coincide and so don't define a direction.
+    pub fn try_from_points(start: Point, end: Point) -> Option<Self> {
+        check_finite(&[start.x, start.y, start.z, end.x, end.y, end.z]).ok()?;
+        let direction = safe_normalized(end - start, scale_of(&[start, end]))?;
+        Some(Self::new(start, direction))
+    }
+
+    /// The interval of parameters along the line that lie within a distance `r` of a point, in
+    /// increasing order, or `None` if the line passes farther away than that
+    pub fn within_distance(&self, p: Point, r: f32) -> Option<(f32, f32)> {
+        Sphere::new(p, r).line_interval(self)
+    }
+
+    /// The distance from the line to the surface of a sphere, which is negative when the line
+    /// passes through the sphere, down to minus the radius through its center.
+    pub fn signed_distance_to_sphere(&self, sphere: &Sphere) -> f32 {
+        self.distance(&sphere.center) - sphere.radius
+    }
+}
+
+impl Default for Line {
+    /// A line through the origin, along the Z axis
+    fn default() -> Self {
+        Self::new(Point::zero(), Vector3::new(0.0, 0.0, 1.0))
+    }
+}
+
+impl std::ops::Add<Vector3> for Line {
+    type Output = Line;
+
+    /// Translate by the given vector
+    fn add(self, v: Vector3) -> Line {
+        Self::new(self.point + v, self.direction)
+    }
+}
+
+impl std::ops::Sub<Vector3> for Line {
+    type Output = Line;
+
+    /// Translate by the opposite of the given vector
+    fn sub(self, v: Vector3) -> Line {
+        Self::new(self.point - v, self.direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let line = Line::default();
+        assert_eq!(line.point, Point::zero());
+        assert_eq!(line.direction.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_try_from_points() {
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(Line::try_from_points(p, p), None);
+        assert_eq!(
+            Line::try_from_points(p, Point::new(f32::NAN, 0.0, 0.0)),
+            None
+        );
+
+        // short segments still define a direction
+        let line = Line::try_from_points(p, Point::new(1.0001, 2.0, 3.0)).unwrap();
+        assert!((line.direction - Vector3::new(1.0, 0.0, 0.0)).magnitude() < 1e-3);
+
+        let line = Line::try_from_points(p, Point::new(1.0, 2.0, 5.0)).unwrap();
+        assert_eq!(line, Line::new(p, Vector3::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_within_distance() {
+        let line = Line::new(Point::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+
+        // a point on the line gives an interval either side of it
+        assert_eq!(
+            line.within_distance(Point::new(3.0, 0.0, 0.0), 2.0),
+            Some((1.0, 5.0))
+        );
+
+        // a point off to the side narrows the interval
+        let (s, t) = line
+            .within_distance(Point::new(3.0, 0.0, 1.2), 2.0)
+            .unwrap();
+        assert!((s - 1.4).abs() < 1e-6 && (t - 4.6).abs() < 1e-6);
+
+        // and one beyond the radius excludes it entirely
+        assert_eq!(line.within_distance(Point::new(3.0, 2.5, 0.0), 2.0), None);
+    }
 }
@@ -1,7 +1,20 @@
-use mini_math::Point;
+use mini_math::{Point, Vector3};
+
+use crate::{closest_parameters, safe_normalized, scale_of, Distance, Triangle, LINEAR_EPSILON};
+
+/// How two line segments overlap one another
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentOverlap {
+    /// The segments don't touch
+    None,
+    /// The segments cross or touch at a single point
+    Point(Point),
+    /// The segments are collinear, and share a section of their length
+    Collinear(LineSegment),
+}
 
 /// A finite line segment
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct LineSegment {
     /// The start point of the line segment
     pub start: Point,
@@ -14,4 +27,192 @@ impl LineSegment {
     pub fn new(start: Point, end: Point) -> Self {
         Self { start, end }
     }
+
+    /// The unit direction from the start to the end of the segment, or `None` if the ends
+    /// coincide
+    pub fn direction(&self) -> Option<Vector3> {
+        safe_normalized(self.end - self.start, scale_of(&[self.start, self.end]))
+    }
+
+    /// The point at the given parameter along the segment, where 0 is the start and 1 is the end
+    pub(crate) fn point_at(&self, t: f32) -> Point {
+        self.start + (self.end - self.start) * t
+    }
+
+    /// The point on this segment closest to a triangle
+    pub fn closest_point_on_segment_to_triangle(&self, triangle: &Triangle) -> Point {
+        triangle.closest_points_to_segment(self).1
+    }
+
+    /// How this segment overlaps another, distinguishing segments which cross at a point from
+    /// collinear segments which share part of their length.
+    ///
+    /// Shared sections run in the same direction as this segment. Points are considered to touch
+    /// within `LINEAR_EPSILON` relative to the coordinates of the segments.
+    pub fn overlap(&self, other: &LineSegment) -> SegmentOverlap {
+        let d1 = self.end - self.start;
+        let d2 = other.end - other.start;
+        let (l1, l2) = (d1.magnitude_squared(), d2.magnitude_squared());
+        let tolerance = LINEAR_EPSILON * scale_of(&[self.start, self.end, other.start, other.end]);
+
+        // a degenerate segment can only touch the other at its single point
+        let (degenerate1, degenerate2) = (l1.sqrt() <= tolerance, l2.sqrt() <= tolerance);
+        if degenerate1 || degenerate2 {
+            let (point, segment) = if degenerate1 {
+                (self.start, other)
+            } else {
+                (other.start, self)
+            };
+            return if segment.distance(&point) <= tolerance {
+                SegmentOverlap::Point(point)
+            } else {
+                SegmentOverlap::None
+            };
+        }
+
+        // only segments which lie along the same line can share part of their length
+        let off_line = |p: Point| (p - self.start).cross(d1).magnitude() / l1.sqrt();
+        let collinear = off_line(other.start) <= tolerance && off_line(other.end) <= tolerance;
+        if !collinear {
+            let (s, t) = self.closest_parameters(other);
+            let (p, q) = (self.point_at(s), other.point_at(t));
+            return if (q - p).magnitude() <= tolerance {
+                SegmentOverlap::Point(p + (q - p) * 0.5)
+            } else {
+                SegmentOverlap::None
+            };
+        }
+
+        let t0 = (other.start - self.start).dot(d1) / l1;
+        let t1 = (other.end - self.start).dot(d1) / l1;
+        let lo = t0.min(t1).max(0.0);
+        let hi = t0.max(t1).min(1.0);
+
+        let slack = tolerance / l1.sqrt();
+        if lo > hi + slack {
+            SegmentOverlap::None
+        } else if hi - lo <= slack {
+            SegmentOverlap::Point(self.point_at((lo + hi) * 0.5))
+        } else {
+            SegmentOverlap::Collinear(LineSegment::new(self.point_at(lo), self.point_at(hi)))
+        }
+    }
+
+    /// Parameters of the closest points between this segment and another, each clamped to [0, 1]
+    pub(crate) fn closest_parameters(&self, other: &LineSegment) -> (f32, f32) {
+        closest_parameters(
+            self.start,
+            self.end - self.start,
+            0.0..=1.0,
+            other.start,
+            other.end - other.start,
+            0.0..=1.0,
+        )
+    }
+}
+
+impl Default for LineSegment {
+    /// A unit segment from the origin, along +Z
+    fn default() -> Self {
+        Self::new(Point::zero(), Point::new(0.0, 0.0, 1.0))
+    }
+}
+
+impl std::ops::Add<Vector3> for LineSegment {
+    type Output = LineSegment;
+
+    /// Translate by the given vector
+    fn add(self, v: Vector3) -> LineSegment {
+        Self::new(self.start + v, self.end + v)
+    }
+}
+
+impl std::ops::Sub<Vector3> for LineSegment {
+    type Output = LineSegment;
+
+    /// Translate by the opposite of the given vector
+    fn sub(self, v: Vector3) -> LineSegment {
+        Self::new(self.start - v, self.end - v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let segment = LineSegment::default();
+        assert_eq!(segment.start, Point::zero());
+        assert_eq!((segment.end - segment.start).magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_direction() {
+        let segment = LineSegment::new(Point::new(1.0, 1.0, 1.0), Point::new(1.0, 4.0, 5.0));
+        assert_eq!(segment.direction(), Some(Vector3::new(0.0, 0.6, 0.8)));
+
+        let segment = LineSegment::new(Point::new(1.0, 1.0, 1.0), Point::new(1.0, 1.0, 1.0));
+        assert_eq!(segment.direction(), None);
+
+        // a tenth of a millimetre is still long enough to define a direction
+        let segment = LineSegment::new(Point::new(1.0, 1.0, 1.0), Point::new(1.0, 1.0001, 1.0));
+        let direction = segment.direction().unwrap();
+        assert!((direction - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-3);
+    }
+
+    #[test]
+    fn test_overlap() {
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 0.0, 0.0));
+
+        // crossing
+        let other = LineSegment::new(Point::new(1.0, -1.0, 0.0), Point::new(1.0, 1.0, 0.0));
+        assert_eq!(
+            segment.overlap(&other),
+            SegmentOverlap::Point(Point::new(1.0, 0.0, 0.0))
+        );
+
+        // crossing at a shallow angle
+        let other = LineSegment::new(Point::new(0.0, -0.001, 0.0), Point::new(4.0, 0.001, 0.0));
+        match segment.overlap(&other) {
+            SegmentOverlap::Point(p) => assert!((p - Point::new(2.0, 0.0, 0.0)).magnitude() < 1e-5),
+            overlap => panic!("expected a crossing, got {:?}", overlap),
+        }
+
+        // skew, passing above
+        let other = LineSegment::new(Point::new(1.0, -1.0, 1.0), Point::new(1.0, 1.0, 1.0));
+        assert_eq!(segment.overlap(&other), SegmentOverlap::None);
+
+        // parallel, but offset
+        let other = LineSegment::new(Point::new(1.0, 1.0, 0.0), Point::new(3.0, 1.0, 0.0));
+        assert_eq!(segment.overlap(&other), SegmentOverlap::None);
+
+        // collinear and disjoint
+        let other = LineSegment::new(Point::new(5.0, 0.0, 0.0), Point::new(7.0, 0.0, 0.0));
+        assert_eq!(segment.overlap(&other), SegmentOverlap::None);
+
+        // collinear and partially overlapping, in the opposite direction
+        let other = LineSegment::new(Point::new(6.0, 0.0, 0.0), Point::new(3.0, 0.0, 0.0));
+        assert_eq!(
+            segment.overlap(&other),
+            SegmentOverlap::Collinear(LineSegment::new(
+                Point::new(3.0, 0.0, 0.0),
+                Point::new(4.0, 0.0, 0.0)
+            ))
+        );
+
+        // collinear and meeting end to end
+        let other = LineSegment::new(Point::new(4.0, 0.0, 0.0), Point::new(6.0, 0.0, 0.0));
+        assert_eq!(
+            segment.overlap(&other),
+            SegmentOverlap::Point(Point::new(4.0, 0.0, 0.0))
+        );
+
+        // a degenerate segment lying on the other
+        let other = LineSegment::new(Point::new(2.0, 0.0, 0.0), Point::new(2.0, 0.0, 0.0));
+        assert_eq!(
+            segment.overlap(&other),
+            SegmentOverlap::Point(Point::new(2.0, 0.0, 0.0))
+        );
+    }
 }
@@ -1,8 +1,11 @@
-use crate::Triangle;
+use crate::{
+    check_finite, nearly_parallel, Line, LineSegment, Ray, ShapeError, Sphere, Triangle,
+    ANGULAR_EPSILON,
+};
 use mini_math::{Point, Vector3};
 
 /// An infinite plane
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Plane {
     /// The normal that lies perpendicular to the plane
     pub normal: Vector3,
@@ -16,13 +19,55 @@ impl Plane {
         Self { normal, d }
     }
 
-    /// Constructs a plane from three points that lie on the plane
+    /// Construct a plane given the components of the plane equation, rejecting non-finite inputs
+    /// and normals too close to zero length to define a direction
+    pub fn try_new(normal: Vector3, d: f32) -> Result<Self, ShapeError> {
+        check_finite(&[normal.x, normal.y, normal.z, d])?;
+        if normal.magnitude_squared() <= f32::EPSILON {
+            return Err(ShapeError::DegenerateNormal);
+        }
+        Ok(Self::new(normal, d))
+    }
+
+    /// The distance from the plane to a point, which is negative behind the plane
+    pub fn signed_distance(&self, p: &Point) -> f32 {
+        self.normal.dot(Vector3::from(*p)) - self.d
+    }
+
+    /// The square of the signed distance to a point, which keeps its sign, so it is negative
+    /// behind the plane and orders points the same way
+    pub fn signed_distance_squared(&self, p: &Point) -> f32 {
+        let d = self.signed_distance(p);
+        (d * d).copysign(d)
+    }
+
+    /// Whether a point lies in the half-space behind the plane, including the plane itself.
+    ///
+    /// The half-space is unbounded, so this is kept apart from the [`Contains`](crate::Contains)
+    /// trait for solid shapes.
+    pub fn half_space_contains(&self, p: &Point) -> bool {
+        self.signed_distance(p) <= 0.0
+    }
+
+    /// Constructs a plane from three points that lie on the plane.
+    ///
+    /// The normal will be zero if the points are collinear, see `try_from_points`.
     pub fn from_points(p0: Point, p1: Point, p2: Point) -> Self {
         let normal = -(p1 - p0).cross(p2 - p0).normalized();
         let d = Vector3::from(p0).dot(normal);
         Self { normal, d }
     }
 
+    /// Constructs a plane from three points that lie on the plane, or `None` if any are
+    /// non-finite or they are collinear and so don't define a plane.
+    ///
+    /// This shares the degeneracy test of [`Plane::from_triangle_robust`], which is relative to
+    /// the size of the triangle.
+    pub fn try_from_points(p0: Point, p1: Point, p2: Point) -> Option<Self> {
+        check_finite(&[p0.x, p0.y, p0.z, p1.x, p1.y, p1.z, p2.x, p2.y, p2.z]).ok()?;
+        Self::from_triangle_robust(&Triangle::new(p0, p1, p2))
+    }
+
     /// Constructs a plane from a point that lies on the plane, and the normal to the plane
     pub fn from_point_and_normal(p: Point, normal: Vector3) -> Self {
         Self {
@@ -30,6 +75,120 @@ impl Plane {
             d: Vector3::from(p).dot(normal),
         }
     }
+
+    /// Constructs a plane from the coefficients of the equation `ax + by + cz + d = 0`, as used by
+    /// most graphics APIs.
+    ///
+    /// Note that the sign of `d` is the opposite of the `d` stored in this crate's planes, for
+    /// which points on the plane satisfy `normal · p = d`. The coefficients are also rescaled so
+    /// that the normal has unit length.
+    pub fn from_equation(a: f32, b: f32, c: f32, d: f32) -> Self {
+        let normal = Vector3::new(a, b, c);
+        let length = normal.magnitude();
+        Self {
+            normal: normal / length,
+            d: -d / length,
+        }
+    }
+
+    /// Decompose a point into its projection onto the plane, and its signed distance along the normal
+    pub fn decompose(&self, p: Point) -> (Point, f32) {
+        let distance = self.signed_distance(&p);
+        (p - self.normal * distance, distance)
+    }
+
+    /// The angle in radians between the plane and a direction, from 0 when the direction lies
+    /// along the plane, to π/2 when the direction is perpendicular to the plane
+    pub fn angle_to(&self, v: Vector3) -> f32 {
+        let cos = self.normal.normalized().dot(v.normalized()).abs();
+        std::f32::consts::FRAC_PI_2 - cos.min(1.0).acos()
+    }
+
+    /// The parameter along a line at which it crosses the plane, in multiples of the line
+    /// direction, or `None` if the line is parallel to the plane
+    pub fn intersect_line_t(&self, line: &Line) -> Option<f32> {
+        self.crossing(line.point, line.direction)
+    }
+
+    /// The distance along a ray at which it crosses the plane, in multiples of the ray direction,
+    /// or `None` if the ray is parallel to the plane or points away from it
+    pub fn intersect_ray_t(&self, ray: &Ray) -> Option<f32> {
+        self.crossing(ray.origin, ray.direction)
+            .filter(|t| *t >= 0.0)
+    }
+
+    /// The parameter along a line segment at which it crosses the plane, from 0 at the start to
+    /// 1 at the end, or `None` if the segment doesn't cross the plane
+    pub fn intersect_segment_t(&self, segment: &LineSegment) -> Option<f32> {
+        self.crossing(segment.start, segment.end - segment.start)
+            .filter(|t| (0.0..=1.0).contains(t))
+    }
+
+    fn crossing(&self, p: Point, d: Vector3) -> Option<f32> {
+        let n_dot_d = self.normal.dot(d);
+        if nearly_parallel(n_dot_d, d, ANGULAR_EPSILON) {
+            return None;
+        }
+        Some(-self.signed_distance(&p) / n_dot_d)
+    }
+
+    /// Constructs the plane containing a triangle, which remains stable for thin slivers.
+    ///
+    /// The normal is taken from whichever pair of edges gives the largest cross product, and
+    /// `None` is returned if the triangle is too close to degenerate to define a plane, which is
+    /// when its height is less than `ANGULAR_EPSILON` of its longest edge.
+    pub fn from_triangle_robust(t: &Triangle) -> Option<Self> {
+        let [a, b, c] = t.vertices();
+        let cross = [
+            (b - a).cross(c - a),
+            (c - b).cross(a - b),
+            (a - c).cross(b - c),
+        ]
+        .into_iter()
+        .max_by(|u, v| u.magnitude_squared().total_cmp(&v.magnitude_squared()))?;
+
+        let longest_edge_squared = t
+            .edges()
+            .iter()
+            .map(|e| (e.end - e.start).magnitude_squared())
+            .fold(0.0, f32::max);
+        let magnitude = cross.magnitude();
+        if !magnitude.is_finite() || magnitude <= ANGULAR_EPSILON * longest_edge_squared {
+            return None;
+        }
+
+        Some(Self::from_point_and_normal(a, -cross / magnitude))
+    }
+
+    /// The reflection of a point across the plane
+    pub fn mirror_point(&self, p: Point) -> Point {
+        p - self.normal * (2.0 * self.signed_distance(&p))
+    }
+
+    /// The reflection of a sphere across the plane
+    pub fn mirror_sphere(&self, s: &Sphere) -> Sphere {
+        Sphere::new(self.mirror_point(s.center), s.radius)
+    }
+
+    /// The reflection of a triangle across the plane.
+    ///
+    /// A reflection reverses the winding of the vertices, so the last two are swapped to keep
+    /// the front face of the result the mirror image of the original's front face.
+    pub fn mirror_triangle(&self, t: &Triangle) -> Triangle {
+        Triangle::new(
+            self.mirror_point(t.a),
+            self.mirror_point(t.c),
+            self.mirror_point(t.b),
+        )
+    }
+
+    /// The reflection of a line segment across the plane
+    pub fn mirror_segment(&self, segment: &LineSegment) -> LineSegment {
+        LineSegment::new(
+            self.mirror_point(segment.start),
+            self.mirror_point(segment.end),
+        )
+    }
 }
 
 impl From<&Triangle> for Plane {
@@ -37,3 +196,290 @@ impl From<&Triangle> for Plane {
         Plane::from_points(t.a, t.b, t.c)
     }
 }
+
+impl Default for Plane {
+    /// The XZ plane, facing +Y
+    fn default() -> Self {
+        Self::new(Vector3::new(0.0, 1.0, 0.0), 0.0)
+    }
+}
+
+impl std::ops::Neg for Plane {
+    type Output = Plane;
+
+    /// The same plane, facing the opposite way
+    fn neg(self) -> Plane {
+        Self::new(-self.normal, -self.d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Distance, Raycast, RaycastOptions};
+    use mini_math::NearlyEqual;
+
+    #[test]
+    fn test_decompose() {
+        let plane = Plane::from_point_and_normal(
+            Point::new(1.0, 2.0, 3.0),
+            Vector3::new(1.0, 1.0, 0.0).normalized(),
+        );
+
+        let p = Point::new(4.0, -2.0, 7.0);
+        let (projected, distance) = plane.decompose(p);
+
+        assert!((projected + plane.normal * distance).nearly_equals(&p));
+        assert!(plane.distance(&projected) < 1e-6);
+        assert!(distance.nearly_equals(plane.signed_distance(&p)));
+    }
+
+    #[test]
+    fn test_raycast() {
+        let plane =
+            Plane::from_point_and_normal(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let culled = RaycastOptions::default().with_cull_back(true);
+
+        // front facing
+        let ray = Ray::new(Point::new(0.0, 3.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let hit = plane.raycast(&ray).unwrap();
+        assert_eq!(hit.t, 2.0);
+        assert_eq!(hit.point, Point::new(0.0, 1.0, 0.0));
+        assert_eq!(hit.normal, plane.normal);
+        assert_eq!(plane.raycast_with(&ray, &culled), Some(hit));
+
+        // back facing
+        let ray = Ray::new(Point::new(0.0, -3.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.raycast_with(&ray, &culled), None);
+        assert_eq!(
+            plane.raycast(&ray).map(|hit| hit.point),
+            Some(Point::new(0.0, 1.0, 0.0))
+        );
+
+        // pointing away
+        let ray = Ray::new(Point::new(0.0, 3.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.raycast(&ray), None);
+
+        // parallel
+        let ray = Ray::new(Point::new(0.0, 3.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.raycast(&ray), None);
+
+        // beyond the maximum distance
+        let ray = Ray::new(Point::new(0.0, 3.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let opts = RaycastOptions::default().with_max_distance(1.5);
+        assert_eq!(plane.raycast_with(&ray, &opts), None);
+    }
+
+    #[test]
+    fn test_angle_to() {
+        let plane = Plane::from_point_and_normal(Point::zero(), Vector3::new(0.0, 1.0, 0.0));
+
+        assert_eq!(
+            plane.angle_to(Vector3::new(0.0, -3.0, 0.0)),
+            std::f32::consts::FRAC_PI_2
+        );
+        assert_eq!(plane.angle_to(Vector3::new(2.0, 0.0, 0.0)), 0.0);
+        assert!(
+            (plane.angle_to(Vector3::new(1.0, 1.0, 0.0)) - std::f32::consts::FRAC_PI_4).abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_default() {
+        let plane = Plane::default();
+        assert_eq!(plane.normal, Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.distance(&Point::new(3.0, 0.0, -2.0)), 0.0);
+    }
+
+    #[test]
+    fn test_raycast_near_parallel() {
+        let plane = Plane::default();
+
+        // not parallel according to f32::EPSILON, but well within the angular tolerance
+        let ray = Ray::new(Point::new(0.0, -1.0, 0.0), Vector3::new(1.0, 5e-7, 0.0));
+        assert!(plane.normal.dot(ray.direction) > f32::EPSILON);
+        assert_eq!(plane.raycast(&ray), None);
+
+        // a shallow but meaningful angle still hits
+        let ray = Ray::new(Point::new(0.0, -1.0, 0.0), Vector3::new(1.0, 1e-3, 0.0));
+        let p = plane.raycast(&ray).unwrap().point;
+        assert!((p.x - 1000.0).abs() < 1e-2);
+        assert!(p.y.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_from_equation() {
+        // y = 5
+        let plane = Plane::from_equation(0.0, 1.0, 0.0, -5.0);
+        assert_eq!(plane, Plane::new(Vector3::new(0.0, 1.0, 0.0), 5.0));
+        assert_eq!(plane.distance(&Point::new(0.0, 5.0, 0.0)), 0.0);
+        assert_eq!(plane.distance(&Point::new(3.0, 7.0, -2.0)), 2.0);
+
+        // the coefficients are normalised, so 2x - 4 = 0 is the plane x = 2
+        let plane = Plane::from_equation(2.0, 0.0, 0.0, -4.0);
+        assert_eq!(plane, Plane::new(Vector3::new(1.0, 0.0, 0.0), 2.0));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            Plane::try_new(Vector3::new(0.0, 1.0, 0.0), 2.0),
+            Ok(Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0))
+        );
+        assert_eq!(
+            Plane::try_new(Vector3::new(f32::NAN, 1.0, 0.0), 2.0),
+            Err(ShapeError::NonFinite)
+        );
+        assert_eq!(
+            Plane::try_new(Vector3::new(0.0, 1.0, 0.0), f32::NEG_INFINITY),
+            Err(ShapeError::NonFinite)
+        );
+        assert_eq!(
+            Plane::try_new(Vector3::zero(), 2.0),
+            Err(ShapeError::DegenerateNormal)
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+        let flipped = -plane;
+
+        assert_eq!(flipped.normal, Vector3::new(0.0, -1.0, 0.0));
+        let p = Point::new(1.0, 5.0, 3.0);
+        assert_eq!(flipped.signed_distance(&p), -plane.signed_distance(&p));
+        assert_eq!(-flipped, plane);
+    }
+
+    #[test]
+    fn test_from_triangle_robust() {
+        let plane = Plane::from_triangle_robust(&Triangle::default()).unwrap();
+        assert_eq!(plane, Plane::from(&Triangle::default()));
+
+        // a long sliver in the XZ plane, wound the same way as the default triangle
+        let sliver = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1000.0, 0.0, 0.0),
+            Point::new(500.0, 0.0, 0.1),
+        );
+        let plane = Plane::from_triangle_robust(&sliver).unwrap();
+        assert!(plane.normal.nearly_equals(&Vector3::new(0.0, 1.0, 0.0)));
+        assert_eq!(plane.d, 0.0);
+
+        // while one a million times longer than it is high is degenerate
+        let sliver = Triangle::new(sliver.a, sliver.b, Point::new(500.0, 0.0, 0.001));
+        assert_eq!(Plane::from_triangle_robust(&sliver), None);
+
+        // collinear and coincident points don't define a plane
+        let collinear = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 1.0, 1.0),
+            Point::new(2.0, 2.0, 2.0),
+        );
+        assert_eq!(Plane::from_triangle_robust(&collinear), None);
+        let point = Triangle::new(Point::zero(), Point::zero(), Point::zero());
+        assert_eq!(Plane::from_triangle_robust(&point), None);
+    }
+
+    #[test]
+    fn test_intersect_t() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+
+        let line = Line::new(
+            Point::new(1.0, 5.0, 0.0),
+            Vector3::new(0.0, 1.0, 1.0).normalized(),
+        );
+        let t = plane.intersect_line_t(&line).unwrap();
+        assert!(t < 0.0);
+        assert!(plane.distance(&(line.point + line.direction * t)) < 1e-5);
+
+        let ray = Ray::new(Point::new(1.0, 5.0, 0.0), Vector3::new(0.0, -2.0, 1.0));
+        let t = plane.intersect_ray_t(&ray).unwrap();
+        assert_eq!(t, 1.5);
+        assert_eq!(ray.origin + ray.direction * t, Point::new(1.0, 2.0, 1.5));
+
+        // pointing away from, and parallel to, the plane
+        let ray = Ray::new(Point::new(1.0, 5.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.intersect_ray_t(&ray), None);
+        let ray = Ray::new(Point::new(1.0, 5.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.intersect_ray_t(&ray), None);
+
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(4.0, 8.0, 0.0));
+        let t = plane.intersect_segment_t(&segment).unwrap();
+        assert_eq!(t, 0.25);
+        assert_eq!(segment.point_at(t), Point::new(1.0, 2.0, 0.0));
+
+        // stopping short of the plane
+        let segment = LineSegment::new(Point::new(0.0, 0.0, 0.0), Point::new(0.0, 1.0, 0.0));
+        assert_eq!(plane.intersect_segment_t(&segment), None);
+    }
+
+    #[test]
+    fn test_try_from_points() {
+        let triangle = Triangle::default();
+        assert_eq!(
+            Plane::try_from_points(triangle.a, triangle.b, triangle.c),
+            Some(Plane::from(&triangle))
+        );
+
+        // collinear and coincident points don't define a plane
+        let p = Point::new(1.0, 2.0, 3.0);
+        let v = Vector3::new(1.0, -1.0, 0.5);
+        assert_eq!(Plane::try_from_points(p, p + v, p + v * 3.0), None);
+        assert_eq!(Plane::try_from_points(p, p, p), None);
+        assert_eq!(
+            Plane::try_from_points(p, p, Point::new(0.0, f32::INFINITY, 0.0)),
+            None
+        );
+
+        // a 1cm right triangle is small, but not degenerate
+        let plane = Plane::try_from_points(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(0.01, 0.0, 0.0),
+            Point::new(0.0, 0.0, 0.01),
+        )
+        .unwrap();
+        assert!((plane.normal - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn test_half_space_contains() {
+        let plane = Plane::new(Vector3::new(0.0, 1.0, 0.0), 2.0);
+
+        assert!(plane.half_space_contains(&Point::new(5.0, 1.99, -3.0)));
+        assert!(!plane.half_space_contains(&Point::new(5.0, 2.01, -3.0)));
+        assert!(plane.half_space_contains(&Point::new(0.0, -100.0, 0.0)));
+    }
+
+    #[test]
+    fn test_mirror() {
+        let plane =
+            Plane::from_point_and_normal(Point::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+
+        let triangle = Triangle::new(
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(3.0, 1.0, 0.0),
+            Point::new(2.0, 0.0, 1.0),
+        );
+        let mirrored = plane.mirror_triangle(&triangle);
+        assert_eq!(mirrored.a, Point::new(0.0, 0.0, 0.0));
+        assert_eq!(mirrored.b, Point::new(0.0, 0.0, 1.0));
+        assert_eq!(mirrored.c, Point::new(-1.0, 1.0, 0.0));
+
+        let n = Plane::from(&triangle).normal;
+        let expected = n - plane.normal * (2.0 * n.dot(plane.normal));
+        assert!((Plane::from(&mirrored).normal - expected).magnitude() < 1e-6);
+
+        let sphere = plane.mirror_sphere(&Sphere::new(Point::new(4.0, 2.0, 0.0), 0.5));
+        assert_eq!(sphere.center, Point::new(-2.0, 2.0, 0.0));
+        assert_eq!(sphere.radius, 0.5);
+
+        let segment = plane.mirror_segment(&LineSegment::new(
+            Point::new(1.0, 5.0, 0.0),
+            Point::new(-1.0, 0.0, 0.0),
+        ));
+        assert_eq!(segment.start, Point::new(1.0, 5.0, 0.0));
+        assert_eq!(segment.end, Point::new(3.0, 0.0, 0.0));
+    }
+}
@@ -1,7 +1,9 @@
-use mini_math::{Point, Vector3};
+use mini_math::{Matrix4, Point, Vector3};
+
+use crate::closest_parameters;
 
 /// An infinite ray
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Ray {
     /// The starting point of the ray
     pub origin: Point,
@@ -14,4 +16,231 @@ impl Ray {
     pub fn new(origin: Point, direction: Vector3) -> Self {
         Self { origin, direction }
     }
+
+    /// The angle in radians between the directions of this ray and another
+    pub fn angle_to(&self, other: &Ray) -> f32 {
+        let cos = self
+            .direction
+            .normalized()
+            .dot(other.direction.normalized());
+        cos.clamp(-1.0, 1.0).acos()
+    }
+
+    /// The closest points between this ray and another, as a pair of points on this ray and the
+    /// other ray respectively
+    pub fn closest_points(&self, other: &Ray) -> (Point, Point) {
+        let (s, t) = closest_parameters(
+            self.origin,
+            self.direction,
+            0.0..=f32::INFINITY,
+            other.origin,
+            other.direction,
+            0.0..=f32::INFINITY,
+        );
+        (
+            self.origin + self.direction * s,
+            other.origin + other.direction * t,
+        )
+    }
+
+    /// The point the given world distance along the ray, regardless of the magnitude of the
+    /// direction
+    pub fn at_distance(&self, d: f32) -> Point {
+        self.origin + self.direction.normalized() * d
+    }
+
+    /// This ray transformed by a matrix, with the origin transformed as a point and the
+    /// direction as a vector, then normalized so that scaling doesn't change its length
+    pub fn transformed(&self, m: &Matrix4) -> Ray {
+        Ray::new(*m * self.origin, (*m * self.direction).normalized())
+    }
+}
+
+impl Default for Ray {
+    /// A ray from the origin, pointing along +Z
+    fn default() -> Self {
+        Self::new(Point::zero(), Vector3::new(0.0, 0.0, 1.0))
+    }
+}
+
+impl std::ops::Add<Vector3> for Ray {
+    type Output = Ray;
+
+    /// Translate by the given vector
+    fn add(self, v: Vector3) -> Ray {
+        Self::new(self.origin + v, self.direction)
+    }
+}
+
+impl std::ops::Sub<Vector3> for Ray {
+    type Output = Ray;
+
+    /// Translate by the opposite of the given vector
+    fn sub(self, v: Vector3) -> Ray {
+        Self::new(self.origin - v, self.direction)
+    }
+}
+
+/// The ray reflected off a surface at the given point, such as the point and normal of a
+/// [`RayHit`](crate::RayHit). The reflected direction keeps the magnitude of the original.
+pub fn reflect_ray(ray: &Ray, point: Point, normal: Vector3) -> Ray {
+    let n = normal.normalized();
+    Ray::new(point, ray.direction - n * (2.0 * ray.direction.dot(n)))
+}
+
+/// The ray refracted through a surface at the given point, where `eta` is the ratio of the
+/// refractive index on the incoming side to that on the far side.
+///
+/// The normal may face either side of the surface. The refracted direction is normalized, and
+/// `None` is returned in the event of total internal reflection.
+pub fn refract_ray(ray: &Ray, point: Point, normal: Vector3, eta: f32) -> Option<Ray> {
+    let d = ray.direction.normalized();
+    let mut n = normal.normalized();
+    // face the normal against the incoming ray
+    if d.dot(n) > 0.0 {
+        n = -n;
+    }
+
+    let cos_i = -d.dot(n);
+    let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+    if k < 0.0 {
+        return None;
+    }
+
+    Some(Ray::new(point, d * eta + n * (eta * cos_i - k.sqrt())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::NearlyEqual;
+
+    #[test]
+    fn test_angle_to() {
+        let ray = Ray::new(Point::zero(), Vector3::new(0.0, 0.0, 1.0));
+
+        let other = Ray::new(Point::new(1.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 0.0));
+        assert_eq!(ray.angle_to(&other), std::f32::consts::FRAC_PI_2);
+
+        let other = Ray::new(Point::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 3.0));
+        assert_eq!(ray.angle_to(&other), 0.0);
+
+        let other = Ray::new(Point::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, -1.0));
+        assert_eq!(ray.angle_to(&other), std::f32::consts::PI);
+    }
+
+    #[test]
+    fn test_default() {
+        let ray = Ray::default();
+        assert_eq!(ray.origin, Point::zero());
+        assert_eq!(ray.direction.magnitude(), 1.0);
+    }
+
+    #[test]
+    fn test_closest_points() {
+        use mini_math::NearlyEqual;
+
+        let ray = Ray::new(Point::zero(), Vector3::new(1.0, 0.0, 0.0));
+
+        // skew rays, both pointing towards their closest approach
+        let other = Ray::new(Point::new(1.0, 1.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        let (a, b) = ray.closest_points(&other);
+        assert!(a.nearly_equals(&Point::new(1.0, 0.0, 0.0)));
+        assert!(b.nearly_equals(&Point::new(1.0, 1.0, 0.0)));
+
+        // the closest approach of the lines lies behind the origin of the other ray
+        let other = Ray::new(Point::new(1.0, 1.0, 2.0), Vector3::new(0.0, 0.0, 1.0));
+        let (a, b) = ray.closest_points(&other);
+        assert!(a.nearly_equals(&Point::new(1.0, 0.0, 0.0)));
+        assert!(b.nearly_equals(&Point::new(1.0, 1.0, 2.0)));
+
+        // the closest approach lies behind the origin of both rays
+        let other = Ray::new(Point::new(-3.0, 1.0, 2.0), Vector3::new(0.0, 0.0, 1.0));
+        let (a, b) = ray.closest_points(&other);
+        assert!(a.nearly_equals(&Point::zero()));
+        assert!(b.nearly_equals(&Point::new(-3.0, 1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_at_distance() {
+        let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector3::new(0.0, 3.0, 0.0));
+
+        let p = ray.at_distance(6.0);
+        assert_eq!(p, Point::new(1.0, 8.0, 3.0));
+        assert_eq!((p - ray.origin).magnitude(), 6.0);
+    }
+
+    #[test]
+    fn test_translation() {
+        let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector3::new(0.0, 1.0, 0.0));
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        let moved = ray + v;
+        assert_eq!(moved.origin, Point::new(2.0, 0.0, 6.0));
+        assert_eq!(moved.direction, ray.direction);
+        assert_eq!(moved - v, ray);
+    }
+
+    #[test]
+    fn test_reflect_ray() {
+        // coming down at 45 degrees onto the XZ plane
+        let ray = Ray::new(Point::new(-1.0, 1.0, 0.0), Vector3::new(1.0, -1.0, 0.0));
+        let reflected = reflect_ray(&ray, Point::zero(), Vector3::new(0.0, 2.0, 0.0));
+        assert_eq!(reflected.origin, Point::zero());
+        assert_eq!(reflected.direction, Vector3::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_refract_ray() {
+        let ray = Ray::new(Point::new(-1.0, 1.0, 0.0), Vector3::new(1.0, -1.0, 0.0));
+        let normal = Vector3::new(0.0, 1.0, 0.0);
+
+        // matching indices pass straight through
+        let refracted = refract_ray(&ray, Point::zero(), normal, 1.0).unwrap();
+        assert!(refracted
+            .direction
+            .nearly_equals(&ray.direction.normalized()));
+
+        // obeys Snell's law, bending towards the normal when entering a denser medium
+        let refracted = refract_ray(&ray, Point::zero(), normal, 0.75).unwrap();
+        let sin_t = refracted.direction.x;
+        assert!((sin_t - 0.75 * std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+        assert!(refracted.direction.y < 0.0);
+        assert!((refracted.direction.magnitude() - 1.0).abs() < 1e-6);
+
+        // the normal may face either way
+        let flipped = refract_ray(&ray, Point::zero(), -normal, 0.75).unwrap();
+        assert!(flipped.direction.nearly_equals(&refracted.direction));
+
+        // total internal reflection beyond the critical angle, where sin 45° * eta exceeds 1
+        assert!(refract_ray(&ray, Point::zero(), normal, 1.4).is_some());
+        assert!(refract_ray(&ray, Point::zero(), normal, 1.5).is_none());
+    }
+
+    #[test]
+    fn test_transformed() {
+        // scale by (2, 1, 0.5), then translate by (1, 2, 3)
+        let m = Matrix4::from_2d_array([
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.5, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ]);
+
+        let ray = Ray::new(
+            Point::new(1.0, 1.0, 2.0),
+            Vector3::new(1.0, 1.0, 2.0).normalized(),
+        );
+        let transformed = ray.transformed(&m);
+        assert_eq!(transformed.origin, Point::new(3.0, 3.0, 4.0));
+        assert!((transformed.direction.magnitude() - 1.0).abs() < 1e-6);
+        let expected = Vector3::new(2.0, 1.0, 1.0).normalized();
+        assert!((transformed.direction - expected).magnitude() < 1e-6);
+
+        // points along the original ray land on the transformed one
+        let p = m * ray.at_distance(3.0);
+        let offset = p - transformed.origin;
+        assert!(offset.cross(transformed.direction).magnitude() < 1e-5);
+        assert!(offset.dot(transformed.direction) > 0.0);
+    }
 }
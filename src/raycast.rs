@@ -0,0 +1,686 @@
+use crate::{
+    nearly_parallel, ray_interval, Aabb, Capsule, ConvexPolygon, Ellipsoid, Plane, Ray, Sphere,
+    Triangle, ANGULAR_EPSILON,
+};
+use mini_math::{NearlyEqual, Point, Vector3};
+
+/// The result of a raycast
+#[derive(PartialEq, Debug)]
+pub struct RayHit {
+    /// The distance along the ray at which the hit occurs, in multiples of the ray direction
+    pub t: f32,
+    /// The point at which the ray hits the surface
+    pub point: Point,
+    /// The surface normal at the point of the hit
+    pub normal: Vector3,
+}
+
+impl NearlyEqual for &RayHit {
+    fn nearly_equals(self, rhs: Self) -> bool {
+        self.t.nearly_equals(rhs.t)
+            && self.point.nearly_equals(&rhs.point)
+            && self.normal.nearly_equals(&rhs.normal)
+    }
+}
+
+impl RayHit {
+    fn new(t: f32, point: Point, normal: Vector3) -> Self {
+        Self { t, point, normal }
+    }
+}
+
+/// Options controlling a raycast
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RaycastOptions {
+    /// The tolerance on the cosine of the angle between the ray and a surface normal, below which
+    /// the ray is treated as parallel to the surface, for shapes that test for it
+    pub epsilon: f32,
+    /// Whether to reject hits on back faces, for shapes that have them
+    pub cull_back: bool,
+    /// The farthest hit to accept, in multiples of the ray direction
+    pub max_distance: f32,
+}
+
+impl RaycastOptions {
+    /// Replace the tolerance below which a ray is treated as parallel to a surface
+    pub fn with_epsilon(self, epsilon: f32) -> Self {
+        Self { epsilon, ..self }
+    }
+
+    /// Replace whether to reject hits on back faces
+    pub fn with_cull_back(self, cull_back: bool) -> Self {
+        Self { cull_back, ..self }
+    }
+
+    /// Replace the farthest hit to accept
+    pub fn with_max_distance(self, max_distance: f32) -> Self {
+        Self {
+            max_distance,
+            ..self
+        }
+    }
+}
+
+impl Default for RaycastOptions {
+    /// The behaviour of a plain raycast: the crate tolerance, both faces, and no distance limit
+    fn default() -> Self {
+        Self {
+            epsilon: ANGULAR_EPSILON,
+            cull_back: false,
+            max_distance: f32::INFINITY,
+        }
+    }
+}
+
+/// Trait for casting a ray against a shape
+pub trait Raycast {
+    /// Where the ray first hits this shape, if at all
+    fn raycast(&self, ray: &Ray) -> Option<RayHit>;
+
+    /// Where the ray first hits this shape, if at all, subject to the given options.
+    ///
+    /// By default this only applies the maximum distance, and shapes which support the other
+    /// options override it.
+    fn raycast_with(&self, ray: &Ray, opts: &RaycastOptions) -> Option<RayHit> {
+        self.raycast(ray).filter(|hit| hit.t <= opts.max_distance)
+    }
+}
+
+impl Raycast for Ellipsoid {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        // transform the ray into the space where the ellipsoid is a unit sphere
+        let origin = (ray.origin - self.center) / self.radii;
+        let direction = ray.direction / self.radii;
+
+        let a = direction.dot(direction);
+        let b = origin.dot(direction);
+        let c = origin.dot(origin) - 1.0;
+
+        let discriminant = b * b - a * c;
+        // early exit if ray misses the ellipsoid entirely
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let mut t = (-b - root) / a;
+        // if the ray starts inside the ellipsoid, the first hit is on the way out
+        if t < 0.0 {
+            t = (-b + root) / a;
+        }
+
+        // early exit if ellipsoid entirely behind ray
+        if t < 0.0 {
+            return None;
+        }
+
+        // normals transform by the inverse transpose of the scale back into world space
+        let normal = ((origin + direction * t) / self.radii).normalized();
+
+        Some(RayHit::new(t, ray.origin + ray.direction * t, normal))
+    }
+}
+
+/// The component of the ray direction along a surface normal, or `None` if the ray runs parallel
+/// to the surface, or hits its back face while culling
+fn facing(normal: Vector3, ray: &Ray, opts: &RaycastOptions) -> Option<f32> {
+    let n_dot_r = normal.dot(ray.direction);
+    // early exit if ray parallel to plane
+    if nearly_parallel(n_dot_r, ray.direction, opts.epsilon) {
+        return None;
+    }
+
+    // early exit if ray hits the back face
+    if opts.cull_back && n_dot_r > 0.0 {
+        return None;
+    }
+
+    Some(n_dot_r)
+}
+
+impl Raycast for Plane {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        self.raycast_with(ray, &RaycastOptions::default())
+    }
+
+    fn raycast_with(&self, ray: &Ray, opts: &RaycastOptions) -> Option<RayHit> {
+        let n_dot_r = facing(self.normal, ray, opts)?;
+        let t = -self.signed_distance(&ray.origin) / n_dot_r;
+
+        // early exit if plane entirely behind ray, or beyond the maximum distance
+        if t < 0.0 || t > opts.max_distance {
+            return None;
+        }
+
+        Some(RayHit::new(t, ray.origin + ray.direction * t, self.normal))
+    }
+}
+
+impl Raycast for Triangle {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        self.raycast_with(ray, &RaycastOptions::default())
+    }
+
+    fn raycast_with(&self, ray: &Ray, opts: &RaycastOptions) -> Option<RayHit> {
+        let plane = self.plane();
+        let n_dot_r = facing(plane.normal, ray, opts)?;
+
+        let d = plane.normal.dot(ray.origin - self.a);
+        let t = -d / n_dot_r;
+
+        // early exit if triangle entirely behind ray, or beyond the maximum distance
+        if t < 0.0 || t > opts.max_distance {
+            return None;
+        }
+
+        let intersection_point = ray.origin + ray.direction * t;
+        if self.coplanar_point_inside(intersection_point) {
+            Some(RayHit::new(t, intersection_point, plane.normal))
+        } else {
+            None
+        }
+    }
+}
+
+impl Raycast for Capsule {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        let axis = self.axis.end - self.axis.start;
+        let length_squared = axis.magnitude_squared();
+
+        // every point at which the ray crosses the surface, along with the normal there
+        let cylinder = self
+            .ray_cylinder_intersections(ray)
+            .into_iter()
+            .flat_map(|(enter, exit)| [enter, exit])
+            // a ray running along the inside of the cylinder never crosses it
+            .filter(|t| t.is_finite())
+            .filter_map(|t| {
+                let p = ray.origin + ray.direction * t;
+                // only the section of the cylinder between the caps is part of the capsule
+                let s = (p - self.axis.start).dot(axis) / length_squared;
+                let normal = (p - (self.axis.start + axis * s)) / self.radius;
+                (0.0..=1.0).contains(&s).then_some((t, normal))
+            });
+
+        let caps = [(self.axis.start, -axis), (self.axis.end, axis)]
+            .into_iter()
+            .flat_map(|(center, outward)| {
+                ray_interval(ray, &Sphere::new(center, self.radius))
+                    .into_iter()
+                    .flat_map(|(enter, exit)| [enter, exit])
+                    .filter_map(move |t| {
+                        let p = ray.origin + ray.direction * t;
+                        // only the outer hemisphere of each cap is part of the capsule
+                        ((p - center).dot(outward) >= 0.0)
+                            .then_some((t, (p - center) / self.radius))
+                    })
+            });
+
+        // if the ray starts inside the capsule, the first hit is on the way out
+        cylinder
+            .chain(caps)
+            .filter(|(t, _)| *t >= 0.0)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(t, normal)| RayHit::new(t, ray.origin + ray.direction * t, normal))
+    }
+}
+
+impl Raycast for ConvexPolygon {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        self.raycast_with(ray, &RaycastOptions::default())
+    }
+
+    fn raycast_with(&self, ray: &Ray, opts: &RaycastOptions) -> Option<RayHit> {
+        let plane = self.plane();
+        let n_dot_r = facing(plane.normal, ray, opts)?;
+        let t = -plane.signed_distance(&ray.origin) / n_dot_r;
+
+        // early exit if polygon entirely behind ray, or beyond the maximum distance
+        if t < 0.0 || t > opts.max_distance {
+            return None;
+        }
+
+        let intersection_point = ray.origin + ray.direction * t;
+        if self.contains_coplanar(intersection_point) {
+            Some(RayHit::new(t, intersection_point, plane.normal))
+        } else {
+            None
+        }
+    }
+}
+
+impl Raycast for Aabb {
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        let origin = [ray.origin.x, ray.origin.y, ray.origin.z];
+        let direction = [ray.direction.x, ray.direction.y, ray.direction.z];
+        let min = [self.min.x, self.min.y, self.min.z];
+        let max = [self.max.x, self.max.y, self.max.z];
+        let axes = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+            Vector3::new(0.0, 0.0, 1.0),
+        ];
+
+        // the interval of the ray within every slab, along with the faces it enters and exits by
+        let (mut enter, mut enter_normal) = (f32::NEG_INFINITY, Vector3::zero());
+        let (mut exit, mut exit_normal) = (f32::INFINITY, Vector3::zero());
+
+        for i in 0..3 {
+            if direction[i] == 0.0 {
+                // early exit if ray parallel to a slab it lies outside of
+                if origin[i] < min[i] || origin[i] > max[i] {
+                    return None;
+                }
+                continue;
+            }
+
+            let t0 = (min[i] - origin[i]) / direction[i];
+            let t1 = (max[i] - origin[i]) / direction[i];
+            let (near, far, normal) = if t0 < t1 {
+                (t0, t1, -axes[i])
+            } else {
+                (t1, t0, axes[i])
+            };
+
+            if near > enter {
+                enter = near;
+                enter_normal = normal;
+            }
+            if far < exit {
+                exit = far;
+                exit_normal = -normal;
+            }
+        }
+
+        // early exit if the ray misses the box, or the box lies entirely behind the ray
+        if enter > exit || exit < 0.0 {
+            return None;
+        }
+
+        // if the ray starts inside the box, the first hit is on the way out
+        let (t, normal) = if enter >= 0.0 {
+            (enter, enter_normal)
+        } else {
+            (exit, exit_normal)
+        };
+
+        Some(RayHit::new(t, ray.origin + ray.direction * t, normal))
+    }
+}
+
+/// The interval of distances along the ray, in multiples of the ray direction, for which it lies
+/// inside a convex region, or `None` if it never enters.
+///
+/// The region is the intersection of the half-spaces in front of each plane, so the normals face
+/// inwards, as they do for a [`Frustum`](crate::Frustum). The interval starts at zero if the ray
+/// begins inside the region, and ends at infinity if the region is unbounded along the ray.
+pub fn ray_convex_region(ray: &Ray, planes: &[Plane]) -> Option<(f32, f32)> {
+    let mut t_enter = 0.0f32;
+    let mut t_exit = f32::INFINITY;
+
+    for plane in planes {
+        let distance = plane.signed_distance(&ray.origin);
+        let n_dot_r = plane.normal.dot(ray.direction);
+
+        if nearly_parallel(n_dot_r, ray.direction, ANGULAR_EPSILON) {
+            // parallel to the plane, so the ray is either always in front or always behind
+            if distance < 0.0 {
+                return None;
+            }
+            continue;
+        }
+
+        let t = -distance / n_dot_r;
+        if n_dot_r > 0.0 {
+            t_enter = t_enter.max(t);
+        } else {
+            t_exit = t_exit.min(t);
+        }
+
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    Some((t_enter, t_exit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frustum::view_frustum;
+    use crate::{Collision, Intersection};
+
+    #[test]
+    fn test_ray_ellipsoid() {
+        let ellipsoid = Ellipsoid::new(Point::zero(), Vector3::new(2.0, 1.0, 0.5));
+
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            ellipsoid.raycast(&ray),
+            Some(RayHit::new(
+                3.0,
+                Point::new(-2.0, 0.0, 0.0),
+                Vector3::new(-1.0, 0.0, 0.0)
+            ))
+        );
+
+        let ray = Ray::new(Point::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            ellipsoid.raycast(&ray),
+            Some(RayHit::new(
+                4.0,
+                Point::new(0.0, 1.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0)
+            ))
+        );
+
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(
+            ellipsoid.raycast(&ray),
+            Some(RayHit::new(
+                4.5,
+                Point::new(0.0, 0.0, -0.5),
+                Vector3::new(0.0, 0.0, -1.0)
+            ))
+        );
+
+        // miss
+        let ray = Ray::new(Point::new(-5.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ellipsoid.raycast(&ray), None);
+
+        // behind
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(ellipsoid.raycast(&ray), None);
+    }
+
+    #[test]
+    fn test_ray_ellipsoid_off_axis_normal() {
+        let ellipsoid = Ellipsoid::new(Point::zero(), Vector3::new(2.0, 1.0, 1.0));
+
+        let ray = Ray::new(Point::new(1.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let hit = ellipsoid.raycast(&ray).unwrap();
+
+        // the normal follows the gradient of the implicit surface, not the direction from the center
+        let p = hit.point;
+        let expected = Vector3::new(p.x / 4.0, p.y, p.z).normalized();
+        assert!(hit.normal.nearly_equals(&expected));
+        assert!((p.x * p.x / 4.0 + p.y * p.y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ray_triangle() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // parallel
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(triangle.raycast(&ray), None);
+
+        // in front
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(triangle.raycast(&ray), None);
+
+        // past
+        let ray = Ray::new(Point::new(3.0, 1.0, 3.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(triangle.raycast(&ray), None);
+
+        // straight through
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.5), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            triangle.raycast(&ray),
+            Some(RayHit::new(
+                2.0,
+                Point::new(0.0, 0.0, 0.5),
+                Vector3::new(0.0, 1.0, 0.0)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_ray_capsule() {
+        let capsule = Capsule::new(Point::zero(), Point::new(0.0, 2.0, 0.0), 1.0);
+
+        // hitting the cylindrical side
+        let ray = Ray::new(Point::new(-5.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let side = capsule.raycast(&ray).unwrap();
+        assert!(side.nearly_equals(&RayHit::new(
+            4.0,
+            Point::new(-1.0, 1.0, 0.0),
+            Vector3::new(-1.0, 0.0, 0.0)
+        )));
+
+        // hitting the end cap straight on
+        let ray = Ray::new(Point::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let cap = capsule.raycast(&ray).unwrap();
+        assert!(cap.nearly_equals(&RayHit::new(
+            2.0,
+            Point::new(0.0, 3.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0)
+        )));
+
+        // hitting the end cap from the side, where the normal leans along the axis
+        let ray = Ray::new(Point::new(-5.0, 2.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let cap = capsule.raycast(&ray).unwrap();
+        let x = 0.75f32.sqrt();
+        assert!((cap.t - (5.0 - x)).abs() < 1e-6);
+        assert!((cap.normal - Vector3::new(-x, 0.5, 0.0)).magnitude() < 1e-6);
+
+        // the side normal is perpendicular to the axis, and the cap normal is not
+        let axis = capsule.axis.end - capsule.axis.start;
+        assert!(side.normal.dot(axis).abs() < 1e-6);
+        assert!(cap.normal.dot(axis) > 0.0);
+
+        // starting inside the capsule
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert!(capsule.raycast(&ray).unwrap().nearly_equals(&RayHit::new(
+            1.0,
+            Point::new(1.0, 1.0, 0.0),
+            Vector3::new(1.0, 0.0, 0.0)
+        )));
+
+        // passing over the end cap
+        let ray = Ray::new(Point::new(-5.0, 4.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(capsule.raycast(&ray), None);
+
+        // pointing away
+        let ray = Ray::new(Point::new(-5.0, 1.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(capsule.raycast(&ray), None);
+    }
+
+    #[test]
+    fn test_ray_convex_polygon() {
+        let pentagon = (0..5)
+            .map(|i| {
+                let theta = std::f32::consts::TAU * i as f32 / 5.0;
+                Point::new(theta.cos(), 0.0, theta.sin())
+            })
+            .collect();
+        let polygon = ConvexPolygon::try_new(pentagon).unwrap();
+
+        let ray = Ray::new(Point::new(0.2, 5.0, 0.1), Vector3::new(0.0, -1.0, 0.0));
+        let hit = polygon.raycast(&ray).unwrap();
+        assert!((hit.t - 5.0).abs() < 1e-6);
+        assert!(hit.point.nearly_equals(&Point::new(0.2, 0.0, 0.1)));
+        assert!((hit.normal - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+
+        // just outside the middle of the first edge
+        let theta = std::f32::consts::PI / 5.0;
+        let r = theta.cos() + 0.01;
+        let ray = Ray::new(
+            Point::new(r * theta.cos(), 5.0, r * theta.sin()),
+            Vector3::new(0.0, -1.0, 0.0),
+        );
+        assert_eq!(polygon.raycast(&ray), None);
+
+        // back faces are only rejected when culling, and glancing rays with a generous epsilon
+        let opts = RaycastOptions::default();
+        let ray = Ray::new(Point::new(0.2, -5.0, 0.1), Vector3::new(0.0, 1.0, 0.0));
+        assert!(polygon.raycast(&ray).is_some());
+        assert_eq!(polygon.raycast_with(&ray, &opts.with_cull_back(true)), None);
+        let ray = Ray::new(Point::new(-1.0, 0.05, 0.0), Vector3::new(1.0, -0.05, 0.0));
+        assert!(polygon.raycast(&ray).is_some());
+        assert_eq!(polygon.raycast_with(&ray, &opts.with_epsilon(0.1)), None);
+        let opts = opts.with_max_distance(0.5);
+        assert_eq!(polygon.raycast_with(&ray, &opts), None);
+    }
+
+    #[test]
+    fn test_ray_aabb() {
+        let aabb = Aabb::new(Point::new(-1.0, -2.0, -3.0), Point::new(1.0, 2.0, 3.0));
+
+        // entering the -X face head on
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(
+            aabb.raycast(&ray),
+            Some(RayHit::new(
+                4.0,
+                Point::new(-1.0, 0.0, 0.0),
+                Vector3::new(-1.0, 0.0, 0.0)
+            ))
+        );
+
+        // entering the +Y face at an angle
+        let ray = Ray::new(Point::new(0.0, 4.0, 0.0), Vector3::new(0.5, -1.0, 0.0));
+        assert_eq!(
+            aabb.raycast(&ray),
+            Some(RayHit::new(
+                2.0,
+                Point::new(1.0, 2.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0)
+            ))
+        );
+
+        // starting inside, so hitting the +Z face on the way out
+        let ray = Ray::new(Point::zero(), Vector3::new(0.0, 0.0, 2.0));
+        assert_eq!(
+            aabb.raycast(&ray),
+            Some(RayHit::new(
+                1.5,
+                Point::new(0.0, 0.0, 3.0),
+                Vector3::new(0.0, 0.0, 1.0)
+            ))
+        );
+
+        // parallel to a slab, outside of it
+        let ray = Ray::new(Point::new(-5.0, 3.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(aabb.raycast(&ray), None);
+
+        // missing diagonally
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 0.0));
+        assert_eq!(aabb.raycast(&ray), None);
+
+        // pointing away
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(aabb.raycast(&ray), None);
+    }
+
+    #[test]
+    fn test_ray_convex_region() {
+        // the tetrahedron between the origin and the unit points on each axis
+        let planes = [
+            Plane::new(Vector3::new(1.0, 0.0, 0.0), 0.0),
+            Plane::new(Vector3::new(0.0, 1.0, 0.0), 0.0),
+            Plane::new(Vector3::new(0.0, 0.0, 1.0), 0.0),
+            Plane::new(
+                Vector3::new(-1.0, -1.0, -1.0).normalized(),
+                -1.0 / 3.0f32.sqrt(),
+            ),
+        ];
+
+        // entering through the x = 0 face, and leaving through the slanted face
+        let ray = Ray::new(Point::new(-1.0, 0.25, 0.25), Vector3::new(1.0, 0.0, 0.0));
+        let (t_enter, t_exit) = ray_convex_region(&ray, &planes).unwrap();
+        assert!((t_enter - 1.0).abs() < 1e-5);
+        assert!((t_exit - 1.5).abs() < 1e-5);
+
+        // passing beyond the slanted face
+        let ray = Ray::new(Point::new(-1.0, 0.75, 0.75), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_convex_region(&ray, &planes), None);
+
+        // pointing away from the region
+        let ray = Ray::new(Point::new(-1.0, 0.25, 0.25), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(ray_convex_region(&ray, &planes), None);
+
+        // starting inside
+        let ray = Ray::new(Point::new(0.1, 0.1, 0.1), Vector3::new(0.0, 0.0, 1.0));
+        let (t_enter, t_exit) = ray_convex_region(&ray, &planes).unwrap();
+        assert_eq!(t_enter, 0.0);
+        assert!((t_exit - 0.7).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_raycast_options() {
+        let triangle = Triangle::default();
+        let ellipsoid = Ellipsoid::new(Point::new(0.0, 0.0, 10.0), Vector3::new(1.0, 1.0, 1.0));
+
+        // the far hit is cut off, while the near one is kept
+        let ray = Ray::new(Point::new(0.0, 5.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        let opts = RaycastOptions::default().with_max_distance(4.0);
+        assert_eq!(triangle.raycast_with(&ray, &opts), None);
+        let opts = opts.with_max_distance(5.0);
+        assert_eq!(triangle.raycast_with(&ray, &opts), triangle.raycast(&ray));
+
+        let ray = Ray::new(Point::zero(), Vector3::new(0.0, 0.0, 1.0));
+        assert!(ellipsoid.raycast(&ray).is_some());
+        let opts = RaycastOptions::default().with_max_distance(8.0);
+        assert_eq!(ellipsoid.raycast_with(&ray, &opts), None);
+
+        // back faces are only rejected when culling
+        let ray = Ray::new(Point::new(0.0, -1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let opts = RaycastOptions::default();
+        assert_eq!(triangle.raycast_with(&ray, &opts), triangle.raycast(&ray));
+        assert!(triangle.raycast(&ray).is_some());
+        assert_eq!(
+            triangle.raycast_with(&ray, &opts.with_cull_back(true)),
+            None
+        );
+
+        // a generous epsilon treats glancing rays as parallel
+        let ray = Ray::new(Point::new(0.0, 0.01, -2.0), Vector3::new(0.0, -0.01, 1.0));
+        assert!(triangle.raycast(&ray).is_some());
+        assert_eq!(triangle.raycast_with(&ray, &opts.with_epsilon(0.1)), None);
+    }
+
+    #[test]
+    fn test_ray_convex_region_frustum() {
+        let frustum = view_frustum();
+
+        // down the view axis, through the near and far planes
+        let ray = Ray::new(Point::zero(), Vector3::new(0.0, 0.0, -1.0));
+        let (t_enter, t_exit) = ray_convex_region(&ray, &frustum.planes).unwrap();
+        assert!((t_enter - 1.0).abs() < 1e-5);
+        assert!((t_exit - 10.0).abs() < 1e-5);
+
+        // starting inside, and leaving through the right-hand side
+        let ray = Ray::new(Point::new(0.0, 0.0, -4.0), Vector3::new(1.0, 0.0, 0.0));
+        let (t_enter, t_exit) = ray_convex_region(&ray, &frustum.planes).unwrap();
+        assert_eq!(t_enter, 0.0);
+        assert!((t_exit - 4.0).abs() < 1e-5);
+
+        // parallel to the near plane, but in front of it
+        let ray = Ray::new(Point::new(-5.0, 0.0, -0.5), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_convex_region(&ray, &frustum.planes), None);
+    }
+
+    #[test]
+    fn test_parallel_independent_of_length() {
+        let triangle = Triangle::default();
+        let plane = triangle.plane();
+
+        // a long ray, well within the angular tolerance of parallel, though not by its raw dot
+        let ray = Ray::new(
+            Point::new(0.0, 1e-6, -0.5),
+            Vector3::new(0.0, -5e-3, 1000.0),
+        );
+        assert!(plane.normal.dot(ray.direction).abs() > ANGULAR_EPSILON);
+        assert_eq!(triangle.raycast(&ray), None);
+        assert_eq!(plane.raycast(&ray), None);
+        assert!(!triangle.intersects(&ray));
+        assert!(ray.collides(&triangle).is_none());
+        assert!(ray.collides(&plane).is_none());
+    }
+}
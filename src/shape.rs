@@ -0,0 +1,83 @@
+use mini_math::Point;
+
+use crate::{Capsule, Distance, Line, LineSegment, Plane, Ray, Sphere, Triangle};
+
+/// Any one of the supported primitives, for heterogeneous collections of shapes
+#[derive(Debug, Clone)]
+pub enum Shape {
+    /// A sphere
+    Sphere(Sphere),
+    /// A capsule
+    Capsule(Capsule),
+    /// A triangle
+    Triangle(Triangle),
+    /// An infinite plane
+    Plane(Plane),
+    /// A finite line segment
+    LineSegment(LineSegment),
+    /// An infinite line
+    Line(Line),
+    /// An infinite ray
+    Ray(Ray),
+}
+
+/// The index of the shape closest to the given point, and the distance to it.
+///
+/// A point inside a solid shape is at zero distance from it, and a point behind a plane is as
+/// close to it as one the same distance in front.
+pub fn nearest_shape(p: Point, shapes: &[Shape]) -> Option<(usize, f32)> {
+    shapes
+        .iter()
+        .map(|shape| shape.distance(&p))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::Vector3;
+
+    #[test]
+    fn test_nearest_shape() {
+        let shapes = [
+            Shape::Sphere(Sphere::new(Point::new(10.0, 0.0, 0.0), 1.0)),
+            Shape::Plane(Plane::from_point_and_normal(
+                Point::new(0.0, -5.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+            )),
+            Shape::Triangle(Triangle::new(
+                Point::new(-11.0, 0.0, -1.0),
+                Point::new(-9.0, 0.0, -1.0),
+                Point::new(-10.0, 0.0, 1.0),
+            )),
+        ];
+
+        assert_eq!(
+            nearest_shape(Point::new(8.0, 0.0, 0.0), &shapes),
+            Some((0, 1.0))
+        );
+        assert_eq!(
+            nearest_shape(Point::new(0.0, -3.0, 0.0), &shapes),
+            Some((1, 2.0))
+        );
+        assert_eq!(
+            nearest_shape(Point::new(-10.0, 1.0, 0.0), &shapes),
+            Some((2, 1.0))
+        );
+
+        // inside the sphere, which is then at no distance at all
+        assert_eq!(
+            nearest_shape(Point::new(10.0, 0.5, 0.0), &shapes),
+            Some((0, 0.0))
+        );
+
+        // just behind the plane
+        assert_eq!(
+            nearest_shape(Point::new(0.0, -6.0, 0.0), &shapes),
+            Some((1, 1.0))
+        );
+
+        assert_eq!(nearest_shape(Point::zero(), &[]), None);
+    }
+}
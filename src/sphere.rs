@@ -1,7 +1,13 @@
-use mini_math::Point;
+use mini_math::{Matrix4, Point, Vector3};
+
+use crate::{
+    any_perpendicular, check_finite, scale_of, Aabb, Capsule, ClosestPoint, Collision, Contact,
+    Intersection, Line, LineSegment, Ray, Raycast, ShapeError, Triangle, ANGULAR_EPSILON,
+    LINEAR_EPSILON,
+};
 
 /// A sphere
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Sphere {
     /// The center of the sphere
     pub center: Point,
@@ -14,4 +20,788 @@ impl Sphere {
     pub fn new(center: Point, radius: f32) -> Self {
         Self { center, radius }
     }
+
+    /// Construct a sphere from a center point and a radius, rejecting non-finite inputs
+    pub fn try_new(center: Point, radius: f32) -> Result<Self, ShapeError> {
+        check_finite(&[center.x, center.y, center.z, radius])?;
+        Ok(Self::new(center, radius))
+    }
+
+    /// The distance from the surface of the sphere to a point, which is negative inside
+    pub fn signed_distance(&self, p: &Point) -> f32 {
+        (*p - self.center).magnitude() - self.radius
+    }
+
+    /// The square of the signed distance to a point, which keeps its sign, so it is negative
+    /// inside and orders points the same way
+    pub fn signed_distance_squared(&self, p: &Point) -> f32 {
+        let d = self.signed_distance(p);
+        (d * d).copysign(d)
+    }
+
+    /// The surface area of the sphere
+    pub fn surface_area(&self) -> f32 {
+        4.0 * std::f32::consts::PI * self.radius * self.radius
+    }
+
+    /// The inertia tensor of a solid sphere with the given mass, about its center.
+    ///
+    /// mini-math has no 3x3 matrix, so the tensor occupies the upper-left of a `Matrix4`, which
+    /// can be multiplied directly with a `Vector3` angular velocity.
+    pub fn inertia_tensor(&self, mass: f32) -> Matrix4 {
+        let i = 0.4 * mass * self.radius * self.radius;
+        Matrix4::from_2d_array([
+            [i, 0.0, 0.0, 0.0],
+            [0.0, i, 0.0, 0.0],
+            [0.0, 0.0, i, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// A copy of this sphere with the radius grown by the given margin.
+    ///
+    /// Negative margins shrink the sphere, but never below a radius of zero.
+    pub fn inflated(&self, margin: f32) -> Sphere {
+        Sphere::new(self.center, (self.radius + margin).max(0.0))
+    }
+
+    /// The solid angle in steradians that the sphere subtends when viewed from a point, which is
+    /// the full 4π from inside the sphere
+    pub fn solid_angle_from(&self, p: Point) -> f32 {
+        let distance = (p - self.center).magnitude();
+        if distance < self.radius {
+            return 4.0 * std::f32::consts::PI;
+        }
+
+        let sin = self.radius / distance;
+        let cos = (1.0 - sin * sin).max(0.0).sqrt();
+        2.0 * std::f32::consts::PI * (1.0 - cos)
+    }
+
+    /// The smallest sphere enclosing both this sphere and another
+    pub fn merge(&self, other: &Sphere) -> Sphere {
+        let diff = other.center - self.center;
+        let distance = diff.magnitude();
+
+        // one sphere already encloses the other
+        if distance + other.radius <= self.radius {
+            return *self;
+        }
+        if distance + self.radius <= other.radius {
+            return *other;
+        }
+
+        let radius = (distance + self.radius + other.radius) * 0.5;
+        let center = self.center + diff * ((radius - self.radius) / distance);
+        Sphere::new(center, radius)
+    }
+
+    /// The translation that pushes this sphere out of contact with a triangle, if they collide.
+    ///
+    /// This is the [`Collision::mtv`], which moves the sphere along the contact normal by the
+    /// overlap, leaving it just touching the triangle, which no longer counts as colliding.
+    pub fn resolve(&self, tri: &Triangle) -> Option<Vector3> {
+        self.mtv(tri)
+    }
+
+    /// The coefficients `(a, b, c)` of the quadratic `a t² + 2 b t + c = 0`, whose roots are the
+    /// distances along the ray, in multiples of the ray direction, at which it crosses the
+    /// surface of the sphere. With the halved `b`, the discriminant is `b² - a c`.
+    pub fn ray_quadratic(&self, ray: &Ray) -> (f32, f32, f32) {
+        let m = ray.origin - self.center;
+        (
+            ray.direction.dot(ray.direction),
+            m.dot(ray.direction),
+            m.dot(m) - self.radius * self.radius,
+        )
+    }
+
+    /// The interval of parameters along a line that lie within the sphere, in increasing order.
+    ///
+    /// Returns `None` if the line misses the sphere, and a single repeated parameter if the line
+    /// is tangent to it.
+    pub fn line_interval(&self, line: &Line) -> Option<(f32, f32)> {
+        ray_interval(&Ray::new(line.point, line.direction), self)
+    }
+
+    /// The point a fraction `t` of the way along the great-circle arc between two points on the
+    /// surface of the sphere.
+    ///
+    /// Antipodal points are joined by infinitely many great circles, in which case the arc
+    /// passes through an arbitrary point perpendicular to them.
+    pub fn slerp_surface(&self, from: Point, to: Point, t: f32) -> Point {
+        let u0 = (from - self.center).normalized();
+        let u1 = (to - self.center).normalized();
+
+        let cos = u0.dot(u1).clamp(-1.0, 1.0);
+        let direction = if cos > 1.0 - ANGULAR_EPSILON {
+            // nearly coincident, where the arc is indistinguishable from a straight line
+            (u0 * (1.0 - t) + u1 * t).normalized()
+        } else if cos < -1.0 + ANGULAR_EPSILON {
+            let angle = std::f32::consts::PI * t;
+            u0 * angle.cos() + any_perpendicular(u0) * angle.sin()
+        } else {
+            let angle = cos.acos();
+            (u0 * ((1.0 - t) * angle).sin() + u1 * (t * angle).sin()) / angle.sin()
+        };
+
+        self.center + direction * self.radius
+    }
+
+    /// The fraction of the given velocity that this sphere can move before it touches a box.
+    ///
+    /// The motion of the center is cast against the box inflated by the radius, and hits near
+    /// the edges and corners of that box are refined against the rounded edges of the true
+    /// swept volume. Returns zero if the sphere already touches the box, and `None` if it never
+    /// touches the box within the full velocity.
+    pub fn sweep_aabb(&self, velocity: Vector3, aabb: &Aabb) -> Option<f32> {
+        let closest = aabb.closest_point(&self.center);
+        if (closest - self.center).magnitude_squared() <= self.radius * self.radius {
+            return Some(0.0);
+        }
+
+        let ray = Ray::new(self.center, velocity);
+        let hit = aabb.inflated(self.radius).raycast(&ray)?;
+        if hit.t > 1.0 {
+            return None;
+        }
+
+        // hits on the flat faces of the inflated box lie outside the original box on one axis
+        let p = hit.point;
+        let outside = [
+            p.x < aabb.min.x || p.x > aabb.max.x,
+            p.y < aabb.min.y || p.y > aabb.max.y,
+            p.z < aabb.min.z || p.z > aabb.max.z,
+        ];
+        if outside.iter().filter(|o| **o).count() <= 1 {
+            return Some(hit.t);
+        }
+
+        // otherwise the sphere touches an edge or corner of the box
+        aabb.edges()
+            .iter()
+            .filter_map(|edge| {
+                Capsule::new(edge.start, edge.end, self.radius)
+                    .raycast(&ray)
+                    .map(|hit| hit.t)
+            })
+            .filter(|t| *t <= 1.0)
+            .min_by(|a, b| a.total_cmp(b))
+    }
+
+    /// The fraction of the given velocity that this sphere can move before it touches another,
+    /// stationary, sphere.
+    ///
+    /// Returns zero if the spheres already touch, and `None` if they never touch within the
+    /// full velocity.
+    pub fn sweep_sphere(&self, velocity: Vector3, other: &Sphere) -> Option<f32> {
+        let combined = Sphere::new(other.center, self.radius + other.radius);
+        if (self.center - combined.center).magnitude_squared() <= combined.radius * combined.radius
+        {
+            return Some(0.0);
+        }
+
+        // a stationary sphere yields a NaN entry, which is rejected along with misses
+        let (enter, _) = ray_interval(&Ray::new(self.center, velocity), &combined)?;
+        Some(enter).filter(|t| (0.0..=1.0).contains(t))
+    }
+
+    /// The fraction of the given velocity that this sphere can move before it touches another,
+    /// stationary, sphere, along with the contact between them at that moment
+    pub fn sweep_sphere_contact(
+        &self,
+        velocity: Vector3,
+        other: &Sphere,
+    ) -> Option<(f32, Contact)> {
+        let t = self.sweep_sphere(velocity, other)?;
+        let center = self.center + velocity * t;
+        let diff = center - other.center;
+
+        // if the centers coincide, push back against the motion
+        let normal = [diff, -velocity]
+            .into_iter()
+            .find(|v| v.magnitude() > LINEAR_EPSILON * scale_of(&[center, other.center]))
+            .map_or(Vector3::new(1.0, 0.0, 0.0), |v| v.normalized());
+
+        let contact = Contact::with_points(
+            center - normal * self.radius,
+            other.center + normal * other.radius,
+            normal,
+            (self.radius + other.radius - diff.magnitude()).max(0.0),
+        );
+        Some((t, contact))
+    }
+
+    /// Line segments approximating the surface of the sphere, for debug drawing.
+    ///
+    /// This produces `rings` circles of latitude and `segments` meridians between the poles on the
+    /// Y axis, for a total of `segments * (2 * rings + 1)` line segments.
+    pub fn wireframe(&self, rings: u32, segments: u32) -> Vec<LineSegment> {
+        let point = |ring: u32, segment: u32| {
+            let phi = std::f32::consts::PI * ring as f32 / (rings + 1) as f32;
+            let theta = std::f32::consts::TAU * segment as f32 / segments as f32;
+            self.center
+                + Vector3::new(phi.sin() * theta.cos(), phi.cos(), phi.sin() * theta.sin())
+                    * self.radius
+        };
+
+        let mut result = vec![];
+        for ring in 1..=rings {
+            for segment in 0..segments {
+                result.push(LineSegment::new(
+                    point(ring, segment),
+                    point(ring, segment + 1),
+                ));
+            }
+        }
+        for segment in 0..segments {
+            for ring in 0..=rings {
+                result.push(LineSegment::new(
+                    point(ring, segment),
+                    point(ring + 1, segment),
+                ));
+            }
+        }
+        result
+    }
+
+    /// The two points at which lines from an external point touch the sphere, within the plane
+    /// through the center of the sphere with the given normal.
+    ///
+    /// Returns `None` if the point lies inside the sphere.
+    pub fn tangent_points_from(&self, p: Point, plane_normal: Vector3) -> Option<(Point, Point)> {
+        let normal = plane_normal.normalized();
+
+        // project the external point into the plane of the tangents
+        let mut diff = p - self.center;
+        diff -= normal * normal.dot(diff);
+
+        let distance = diff.magnitude();
+        if distance < self.radius || distance == 0.0 {
+            return None;
+        }
+
+        let u = diff / distance;
+        let v = normal.cross(u);
+
+        // the tangent, the radius and the line to the center form a right triangle
+        let cos = self.radius / distance;
+        let sin = (1.0 - cos * cos).sqrt();
+
+        Some((
+            self.center + (u * cos + v * sin) * self.radius,
+            self.center + (u * cos - v * sin) * self.radius,
+        ))
+    }
+
+    /// The point on the surface of the sphere at the given spherical angles.
+    ///
+    /// `theta` is the polar angle down from the +Y axis, and `phi` is the azimuth around the
+    /// Y axis, measured from +X towards +Z.
+    pub fn surface_point(&self, theta: f32, phi: f32) -> Point {
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        self.center
+            + Vector3::new(sin_theta * cos_phi, cos_theta, sin_theta * sin_phi) * self.radius
+    }
+
+    /// The spherical angles `(theta, phi)` of a point on the surface of the sphere, the inverse
+    /// of [`Sphere::surface_point`].
+    ///
+    /// `theta` lies in `[0, π]` and `phi` in `(-π, π]`. At the poles, where the azimuth is
+    /// undefined, `phi` is zero, as are both angles for a point at the center.
+    pub fn spherical_of(&self, p: Point) -> (f32, f32) {
+        let d = p - self.center;
+        let r = d.magnitude();
+        if r <= LINEAR_EPSILON * scale_of(&[p, self.center]) {
+            return (0.0, 0.0);
+        }
+
+        let theta = (d.y / r).clamp(-1.0, 1.0).acos();
+        let phi = if (d.x * d.x + d.z * d.z).sqrt() <= ANGULAR_EPSILON * r {
+            0.0
+        } else {
+            d.z.atan2(d.x)
+        };
+
+        (theta, phi)
+    }
+}
+
+impl Default for Sphere {
+    /// A unit sphere at the origin
+    fn default() -> Self {
+        Self::new(Point::zero(), 1.0)
+    }
+}
+
+impl std::ops::Add<Vector3> for Sphere {
+    type Output = Sphere;
+
+    /// Translate by the given vector
+    fn add(self, v: Vector3) -> Sphere {
+        Self::new(self.center + v, self.radius)
+    }
+}
+
+impl std::ops::Sub<Vector3> for Sphere {
+    type Output = Sphere;
+
+    /// Translate by the opposite of the given vector
+    fn sub(self, v: Vector3) -> Sphere {
+        Self::new(self.center - v, self.radius)
+    }
+}
+
+/// The entry and exit distances of a ray through a sphere, which may be negative if the
+/// sphere lies behind the origin of the ray
+pub(crate) fn ray_interval(ray: &Ray, sphere: &Sphere) -> Option<(f32, f32)> {
+    let (a, b, c) = sphere.ray_quadratic(ray);
+
+    let discriminant = b * b - a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    Some(((-b - root) / a, (-b + root) / a))
+}
+
+/// The interval along a ray that is visible within the shell between two concentric spheres,
+/// treating the inner sphere as opaque (such as a planet beneath its atmosphere).
+///
+/// The interval starts where the ray enters the outer sphere (or at the origin, if it starts
+/// inside the shell), and ends either where the ray exits the outer sphere, or where it first
+/// hits the inner sphere. Returns `None` if the ray never passes through the shell.
+pub fn ray_shell(ray: &Ray, inner: &Sphere, outer: &Sphere) -> Option<(f32, f32)> {
+    let (outer_enter, outer_exit) = ray_interval(ray, outer)?;
+
+    let mut start = outer_enter.max(0.0);
+    let mut end = outer_exit;
+
+    if let Some((inner_enter, inner_exit)) = ray_interval(ray, inner) {
+        if inner_enter >= start {
+            end = end.min(inner_enter);
+        } else if inner_exit > start {
+            // the ray starts inside the inner sphere, and only emerges into the shell later
+            start = inner_exit;
+        }
+    }
+
+    if start < end {
+        Some((start, end))
+    } else {
+        None
+    }
+}
+
+/// Indices of all spheres that intersect the query sphere, in their original order
+pub fn overlapping_spheres(query: &Sphere, spheres: &[Sphere]) -> Vec<usize> {
+    spheres
+        .iter()
+        .enumerate()
+        .filter(|(_, sphere)| query.intersects(*sphere))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Indices of all spheres that intersect the query sphere, along with the depth of penetration,
+/// sorted from the deepest penetration to the shallowest
+pub fn overlapping_spheres_by_penetration(query: &Sphere, spheres: &[Sphere]) -> Vec<(usize, f32)> {
+    let mut result: Vec<(usize, f32)> = overlapping_spheres(query, spheres)
+        .into_iter()
+        .map(|i| {
+            let sphere = &spheres[i];
+            let distance = (sphere.center - query.center).magnitude();
+            (i, query.radius + sphere.radius - distance)
+        })
+        .collect();
+
+    result.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mini_math::NearlyEqual;
+
+    #[test]
+    fn test_tangent_points_from() {
+        let sphere = Sphere::new(Point::zero(), 1.0);
+        let normal = Vector3::new(0.0, 0.0, 1.0);
+
+        let (a, b) = sphere
+            .tangent_points_from(Point::new(2.0, 0.0, 0.0), normal)
+            .unwrap();
+        let expected_y = 3.0f32.sqrt() / 2.0;
+        assert!(a.nearly_equals(&Point::new(0.5, expected_y, 0.0)));
+        assert!(b.nearly_equals(&Point::new(0.5, -expected_y, 0.0)));
+
+        // each tangent is perpendicular to the radius at the point of contact
+        for t in [a, b] {
+            let radius = t - sphere.center;
+            let tangent = Point::new(2.0, 0.0, 0.0) - t;
+            assert!(radius.dot(tangent).abs() < 1e-6);
+        }
+
+        assert_eq!(
+            sphere.tangent_points_from(Point::new(0.5, 0.0, 0.0), normal),
+            None
+        );
+    }
+
+    #[test]
+    fn test_overlapping_spheres() {
+        let query = Sphere::new(Point::zero(), 1.0);
+        let spheres = [
+            Sphere::new(Point::new(1.5, 0.0, 0.0), 1.0),
+            Sphere::new(Point::new(5.0, 0.0, 0.0), 1.0),
+            Sphere::new(Point::new(0.0, 0.5, 0.0), 1.0),
+            Sphere::new(Point::new(0.0, 0.0, 2.0), 1.0),
+            Sphere::new(Point::new(0.0, -3.0, 0.0), 1.0),
+            Sphere::new(Point::new(-1.0, 0.0, 0.0), 0.5),
+        ];
+
+        assert_eq!(overlapping_spheres(&query, &spheres), vec![0, 2, 3, 5]);
+
+        assert_eq!(
+            overlapping_spheres_by_penetration(&query, &spheres),
+            vec![(2, 1.5), (0, 0.5), (5, 0.5), (3, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_inflated() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+
+        let inflated = sphere.inflated(0.5);
+        assert_eq!(inflated.center, sphere.center);
+        assert_eq!(inflated.radius, 2.5);
+
+        assert_eq!(sphere.inflated(-5.0).radius, 0.0);
+    }
+
+    #[test]
+    fn test_ray_shell() {
+        let inner = Sphere::new(Point::zero(), 1.0);
+        let outer = Sphere::new(Point::zero(), 2.0);
+
+        // missing the inner sphere
+        let ray = Ray::new(Point::new(-5.0, 1.5, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let (start, end) = ray_shell(&ray, &inner, &outer).unwrap();
+        let half_chord = (4.0f32 - 1.5 * 1.5).sqrt();
+        assert!((start - (5.0 - half_chord)).abs() < 1e-6);
+        assert!((end - (5.0 + half_chord)).abs() < 1e-6);
+
+        // occluded by the inner sphere
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_shell(&ray, &inner, &outer), Some((3.0, 4.0)));
+
+        // starting within the shell
+        let ray = Ray::new(Point::new(-1.5, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_shell(&ray, &inner, &outer), Some((0.0, 0.5)));
+
+        // starting within the inner sphere
+        let ray = Ray::new(Point::zero(), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_shell(&ray, &inner, &outer), Some((1.0, 2.0)));
+
+        // missing both
+        let ray = Ray::new(Point::new(-5.0, 3.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray_shell(&ray, &inner, &outer), None);
+
+        // pointing away
+        let ray = Ray::new(Point::new(-5.0, 0.0, 0.0), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(ray_shell(&ray, &inner, &outer), None);
+    }
+
+    #[test]
+    fn test_default() {
+        let sphere = Sphere::default();
+        assert_eq!(sphere.center, Point::zero());
+        assert_eq!(sphere.radius, 1.0);
+    }
+
+    #[test]
+    fn test_copy() {
+        let a = Sphere::new(Point::new(1.0, 2.0, 3.0), 1.0);
+        let mut b = a;
+        b.center = Point::zero();
+        b.radius = 2.0;
+
+        assert_eq!(a, Sphere::new(Point::new(1.0, 2.0, 3.0), 1.0));
+        assert_eq!(b, Sphere::new(Point::zero(), 2.0));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_wireframe() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+
+        let wireframe = sphere.wireframe(3, 8);
+        assert_eq!(wireframe.len(), 8 * (2 * 3 + 1));
+
+        for segment in &wireframe {
+            assert!(((segment.start - sphere.center).magnitude() - sphere.radius).abs() < 1e-5);
+            assert!(((segment.end - sphere.center).magnitude() - sphere.radius).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_resolve() {
+        let triangle = Triangle::default();
+
+        // resting on the face
+        let sphere = Sphere::new(Point::new(0.0, 0.5, 0.0), 1.0);
+        let translation = sphere.resolve(&triangle).unwrap();
+        assert!(translation.nearly_equals(&Vector3::new(0.0, 0.5, 0.0)));
+        assert_eq!(Some(translation), sphere.mtv(&triangle));
+        let resolved = Sphere::new(sphere.center + translation, sphere.radius);
+        assert!(resolved.collides(&triangle).is_none());
+
+        // overlapping an edge
+        let sphere = Sphere::new(Point::new(0.0, 0.5, -1.5), 1.0);
+        let translation = sphere.resolve(&triangle).unwrap();
+        let resolved = Sphere::new(sphere.center + translation, sphere.radius);
+        assert!(
+            resolved
+                .signed_distance(&triangle.closest_point(&resolved.center))
+                .abs()
+                < 1e-5
+        );
+        assert!(resolved.collides(&triangle).is_none());
+
+        let sphere = Sphere::new(Point::new(0.0, 2.0, 0.0), 1.0);
+        assert_eq!(sphere.resolve(&triangle), None);
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            Sphere::try_new(Point::new(1.0, 2.0, 3.0), 1.0),
+            Ok(Sphere::new(Point::new(1.0, 2.0, 3.0), 1.0))
+        );
+        assert_eq!(
+            Sphere::try_new(Point::zero(), f32::NAN),
+            Err(ShapeError::NonFinite)
+        );
+        assert_eq!(
+            Sphere::try_new(Point::new(f32::INFINITY, 0.0, 0.0), 1.0),
+            Err(ShapeError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn test_mass_properties() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        assert_eq!(sphere.surface_area(), 16.0 * std::f32::consts::PI);
+
+        let tensor = sphere.inertia_tensor(5.0);
+        let expected = 0.4 * 5.0 * 4.0;
+        assert_eq!(
+            tensor * Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(expected, expected * 2.0, expected * 3.0)
+        );
+        assert_eq!(tensor.0[0][1], 0.0);
+        assert_eq!(tensor.0[2][0], 0.0);
+    }
+
+    #[test]
+    fn test_line_interval() {
+        let sphere = Sphere::new(Point::new(1.0, 0.0, 0.0), 2.0);
+
+        // through the center
+        let line = Line::new(Point::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(sphere.line_interval(&line), Some((-2.0, 2.0)));
+
+        // through the center, from behind the sphere
+        let line = Line::new(Point::new(1.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(sphere.line_interval(&line), Some((-7.0, -3.0)));
+
+        // tangent
+        let line = Line::new(Point::new(1.0, 2.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(sphere.line_interval(&line), Some((0.0, 0.0)));
+
+        let line = Line::new(Point::new(1.0, 3.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(sphere.line_interval(&line), None);
+    }
+
+    #[test]
+    fn test_translation() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(sphere + v, Sphere::new(Point::new(2.0, 0.0, 6.0), 2.0));
+        assert_eq!(sphere - v, Sphere::new(Point::new(0.0, 4.0, 0.0), 2.0));
+    }
+
+    #[test]
+    fn test_slerp_surface() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        let from = Point::new(3.0, 2.0, 3.0);
+        let to = Point::new(1.0, 4.0, 3.0);
+
+        assert!(sphere.slerp_surface(from, to, 0.0).nearly_equals(&from));
+        assert!(sphere.slerp_surface(from, to, 1.0).nearly_equals(&to));
+
+        // halfway around a quarter circle
+        let mid = sphere.slerp_surface(from, to, 0.5);
+        let h = 2.0f32.sqrt();
+        assert!((mid - Point::new(1.0 + h, 2.0 + h, 3.0)).magnitude() < 1e-5);
+        assert!(((mid - sphere.center).magnitude() - sphere.radius).abs() < 1e-5);
+
+        // antipodal points still pass around the surface, a quarter turn from each end
+        let to = Point::new(-1.0, 2.0, 3.0);
+        let mid = sphere.slerp_surface(from, to, 0.5);
+        assert!(((mid - sphere.center).magnitude() - sphere.radius).abs() < 1e-5);
+        assert!((mid - sphere.center).dot(from - sphere.center).abs() < 1e-5);
+        assert!((sphere.slerp_surface(from, to, 1.0) - to).magnitude() < 1e-5);
+    }
+
+    #[test]
+    fn test_sweep_aabb() {
+        let aabb = Aabb::new(Point::new(-1.0, -1.0, -1.0), Point::new(1.0, 1.0, 1.0));
+
+        // approaching a face head on
+        let sphere = Sphere::new(Point::new(-5.0, 0.0, 0.0), 1.0);
+        let t = sphere
+            .sweep_aabb(Vector3::new(10.0, 0.0, 0.0), &aabb)
+            .unwrap();
+        assert!((t - 0.3).abs() < 1e-6);
+
+        // stopping short of the face
+        assert_eq!(sphere.sweep_aabb(Vector3::new(2.0, 0.0, 0.0), &aabb), None);
+
+        // approaching a corner along the diagonal
+        let sphere = Sphere::new(Point::new(-5.0, -5.0, -5.0), 1.0);
+        let t = sphere
+            .sweep_aabb(Vector3::new(10.0, 10.0, 10.0), &aabb)
+            .unwrap();
+        assert!((t - (4.0 - 1.0 / 3.0f32.sqrt()) / 10.0).abs() < 1e-5);
+
+        // passing by an edge, inside the inflated box but outside the rounded swept volume
+        let sphere = Sphere::new(Point::new(-5.0, 1.9, 1.9), 1.0);
+        assert_eq!(sphere.sweep_aabb(Vector3::new(10.0, 0.0, 0.0), &aabb), None);
+
+        // already touching
+        let sphere = Sphere::new(Point::new(1.5, 0.0, 0.0), 1.0);
+        assert_eq!(
+            sphere.sweep_aabb(Vector3::new(1.0, 0.0, 0.0), &aabb),
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn test_sweep_sphere() {
+        let other = Sphere::new(Point::new(10.0, 0.0, 0.0), 1.0);
+
+        // head on, touching once the centers are 3 apart
+        let sphere = Sphere::new(Point::zero(), 2.0);
+        let velocity = Vector3::new(10.0, 0.0, 0.0);
+        assert_eq!(sphere.sweep_sphere(velocity, &other), Some(0.7));
+
+        let (t, contact) = sphere.sweep_sphere_contact(velocity, &other).unwrap();
+        assert_eq!(t, 0.7);
+        assert_eq!(contact.normal, Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq!(contact.point, Point::new(9.0, 0.0, 0.0));
+        assert_eq!(contact.point_a, contact.point_b);
+        assert_eq!(contact.overlap, 0.0);
+
+        // stopping short, passing to one side, and heading away
+        assert_eq!(sphere.sweep_sphere(velocity * 0.5, &other), None);
+        assert_eq!(
+            sphere.sweep_sphere(Vector3::new(10.0, 5.0, 0.0), &other),
+            None
+        );
+        assert_eq!(sphere.sweep_sphere(-velocity, &other), None);
+
+        // already overlapping, and stationary
+        let sphere = Sphere::new(Point::new(8.0, 0.0, 0.0), 2.0);
+        assert_eq!(sphere.sweep_sphere(velocity, &other), Some(0.0));
+        let sphere = Sphere::new(Point::zero(), 2.0);
+        assert_eq!(sphere.sweep_sphere(Vector3::zero(), &other), None);
+    }
+
+    #[test]
+    fn test_merge() {
+        // disjoint, so the merged sphere spans from the far side of one to the far side of the other
+        let a = Sphere::new(Point::new(-2.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(Point::new(3.0, 0.0, 0.0), 2.0);
+        let merged = a.merge(&b);
+        assert_eq!(merged, Sphere::new(Point::new(1.0, 0.0, 0.0), 4.0));
+        assert_eq!(b.merge(&a), merged);
+
+        // one inside the other
+        let outer = Sphere::new(Point::new(1.0, 1.0, 1.0), 5.0);
+        let inner = Sphere::new(Point::new(2.0, 1.0, 0.0), 1.0);
+        assert_eq!(outer.merge(&inner), outer);
+        assert_eq!(inner.merge(&outer), outer);
+
+        // concentric
+        let smaller = Sphere::new(Point::new(1.0, 1.0, 1.0), 2.0);
+        assert_eq!(smaller.merge(&outer), outer);
+    }
+
+    #[test]
+    fn test_solid_angle_from() {
+        use std::f32::consts::PI;
+
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 1.0);
+
+        // far away, approaching the area of the disc over the distance squared
+        let angle = sphere.solid_angle_from(Point::new(1.0, 2.0, 103.0));
+        assert!((angle - PI / 10000.0).abs() < 1e-6);
+
+        // at the surface, half of all directions are covered
+        let angle = sphere.solid_angle_from(Point::new(2.0, 2.0, 3.0));
+        assert!((angle - 2.0 * PI).abs() < 1e-6);
+
+        // inside, all of them are
+        assert_eq!(sphere.solid_angle_from(Point::new(1.5, 2.0, 3.0)), 4.0 * PI);
+    }
+
+    #[test]
+    fn test_ray_quadratic() {
+        let sphere = Sphere::new(Point::new(0.0, 0.0, 5.0), 1.0);
+
+        // rays from the origin towards the sphere, fanning out past its edge
+        for i in 0..=20 {
+            let ray = Ray::new(Point::zero(), Vector3::new(i as f32 * 0.02, 0.05, 1.0));
+            let (a, b, c) = sphere.ray_quadratic(&ray);
+            assert_eq!(b * b - a * c > 0.0, sphere.intersects(&ray));
+        }
+
+        // the roots are where the ray crosses the surface
+        let ray = Ray::new(Point::zero(), Vector3::new(0.0, 0.0, 2.0));
+        assert_eq!(sphere.ray_quadratic(&ray), (4.0, -10.0, 24.0));
+        let (a, b, c) = sphere.ray_quadratic(&ray);
+        let root = (b * b - a * c).sqrt();
+        assert_eq!(((-b - root) / a, (-b + root) / a), (2.0, 3.0));
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let sphere = Sphere::new(Point::new(1.0, -2.0, 3.0), 2.5);
+
+        for &(theta, phi) in &[
+            (0.3, 0.0),
+            (1.2, 2.0),
+            (2.9, -1.5),
+            (std::f32::consts::FRAC_PI_2, 3.0),
+        ] {
+            let p = sphere.surface_point(theta, phi);
+            assert!((sphere.signed_distance(&p)).abs() < 1e-5);
+
+            let (t, f) = sphere.spherical_of(p);
+            assert!((t - theta).abs() < 1e-5);
+            assert!((f - phi).abs() < 1e-5);
+        }
+
+        let north = sphere.surface_point(0.0, 1.0);
+        assert_eq!(sphere.spherical_of(north), (0.0, 0.0));
+        let (t, f) = sphere.spherical_of(sphere.surface_point(std::f32::consts::PI, 1.0));
+        assert!((t - std::f32::consts::PI).abs() < 1e-5);
+        assert_eq!(f, 0.0);
+    }
 }
@@ -0,0 +1,149 @@
+use mini_math::{Point, Vector3};
+
+use crate::{safe_normalized, Capsule, LineSegment, Sphere, Triangle};
+
+/// Trait for finding the point of a convex shape that lies farthest in a given direction, as
+/// used by GJK and EPA
+pub trait SupportMap {
+    /// The point of this shape farthest along the direction, which need not be normalized
+    fn support(&self, direction: Vector3) -> Point;
+}
+
+/// The unit vector along a direction, falling back to +Y for a zero direction, for which every
+/// point is equally far. A direction carries no coordinate scale, so only zero is rejected.
+fn unit_direction(direction: Vector3) -> Vector3 {
+    safe_normalized(direction, 0.0).unwrap_or(Vector3::new(0.0, 1.0, 0.0))
+}
+
+/// The point farthest along the direction, preferring the earliest in the event of a tie
+fn farthest(points: &[Point], direction: Vector3) -> Point {
+    let mut result = points[0];
+    for p in &points[1..] {
+        if Vector3::from(*p).dot(direction) > Vector3::from(result).dot(direction) {
+            result = *p;
+        }
+    }
+    result
+}
+
+impl SupportMap for Sphere {
+    fn support(&self, direction: Vector3) -> Point {
+        self.center + unit_direction(direction) * self.radius
+    }
+}
+
+impl SupportMap for Capsule {
+    fn support(&self, direction: Vector3) -> Point {
+        self.axis.support(direction) + unit_direction(direction) * self.radius
+    }
+}
+
+impl SupportMap for Triangle {
+    fn support(&self, direction: Vector3) -> Point {
+        farthest(&self.vertices(), direction)
+    }
+}
+
+impl SupportMap for LineSegment {
+    fn support(&self, direction: Vector3) -> Point {
+        farthest(&[self.start, self.end], direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directions() -> Vec<Vector3> {
+        vec![
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+            Vector3::new(0.0, 0.0, 3.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(-2.0, 0.5, 1.0),
+        ]
+    }
+
+    fn assert_farthest<S: SupportMap>(shape: &S, samples: &[Point]) {
+        for direction in directions() {
+            let support = Vector3::from(shape.support(direction)).dot(direction);
+            for sample in samples {
+                assert!(Vector3::from(*sample).dot(direction) <= support + 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sphere_support() {
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        assert_eq!(
+            sphere.support(Vector3::new(0.0, 5.0, 0.0)),
+            Point::new(1.0, 4.0, 3.0)
+        );
+
+        let samples: Vec<Point> = sphere
+            .wireframe(8, 16)
+            .iter()
+            .map(|segment| segment.start)
+            .collect();
+        assert_farthest(&sphere, &samples);
+    }
+
+    #[test]
+    fn test_capsule_support() {
+        let capsule = Capsule::new(Point::zero(), Point::new(0.0, 2.0, 0.0), 1.0);
+        assert_eq!(
+            capsule.support(Vector3::new(0.0, 1.0, 0.0)),
+            Point::new(0.0, 3.0, 0.0)
+        );
+        assert_eq!(
+            capsule.support(Vector3::new(0.0, -1.0, 0.0)),
+            Point::new(0.0, -1.0, 0.0)
+        );
+
+        let samples: Vec<Point> = capsule
+            .wireframe(16)
+            .iter()
+            .map(|segment| segment.start)
+            .collect();
+        assert_farthest(&capsule, &samples);
+    }
+
+    #[test]
+    fn test_triangle_support() {
+        let triangle = Triangle::default();
+        assert_eq!(triangle.support(Vector3::new(0.0, 0.0, 1.0)), triangle.c);
+        assert_eq!(triangle.support(Vector3::new(1.0, 0.0, -1.0)), triangle.b);
+
+        let mut samples = vec![];
+        for i in 0..=10 {
+            for j in 0..=(10 - i) {
+                let (v, w) = (i as f32 / 10.0, j as f32 / 10.0);
+                samples.push(triangle.point_from_barycentric(Vector3::new(1.0 - v - w, v, w)));
+            }
+        }
+        assert_farthest(&triangle, &samples);
+    }
+
+    #[test]
+    fn test_segment_support() {
+        let segment = LineSegment::new(Point::new(-1.0, 0.0, 0.0), Point::new(1.0, 2.0, 0.0));
+        assert_eq!(segment.support(Vector3::new(0.0, 1.0, 0.0)), segment.end);
+        assert_eq!(segment.support(Vector3::new(-1.0, 0.0, 0.0)), segment.start);
+
+        let samples: Vec<Point> = (0..=10)
+            .map(|i| segment.point_at(i as f32 / 10.0))
+            .collect();
+        assert_farthest(&segment, &samples);
+    }
+
+    #[test]
+    fn test_zero_direction() {
+        // every point is equally far along a zero direction, but the support is still on the surface
+        let sphere = Sphere::new(Point::new(1.0, 2.0, 3.0), 2.0);
+        assert_eq!(sphere.support(Vector3::zero()), Point::new(1.0, 4.0, 3.0));
+
+        let capsule = Capsule::new(Point::zero(), Point::new(2.0, 0.0, 0.0), 1.0);
+        assert_eq!(capsule.support(Vector3::zero()), Point::new(0.0, 1.0, 0.0));
+    }
+}
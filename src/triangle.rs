@@ -1,8 +1,23 @@
-use crate::Plane;
+use crate::{
+    closest_parameters, nearly_parallel, scale_of, Capsule, ClosestPoint, Distance, LineSegment,
+    Plane, Ray, RayHit, Raycast, RaycastOptions, ANGULAR_EPSILON, LINEAR_EPSILON,
+};
 use mini_math::{Point, Vector3};
 
+/// A feature of a triangle, with vertices and edges numbered as in `Triangle::vertices` and
+/// `Triangle::edges`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    /// One of the three vertices
+    Vertex(usize),
+    /// The interior of one of the three edges
+    Edge(usize),
+    /// The interior of the face
+    Face,
+}
+
 /// A triangle
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Triangle {
     pub a: Point,
     pub b: Point,
@@ -15,8 +30,228 @@ impl Triangle {
         Self { a, b, c }
     }
 
+    /// The plane containing the triangle, facing the same way as the triangle
+    pub fn plane(&self) -> Plane {
+        Plane::from(self)
+    }
+
+    /// The vertices of the triangle, in order
+    pub fn vertices(&self) -> [Point; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// The edges of the triangle, in order (a → b, b → c, c → a)
+    pub fn edges(&self) -> [LineSegment; 3] {
+        [
+            LineSegment::new(self.a, self.b),
+            LineSegment::new(self.b, self.c),
+            LineSegment::new(self.c, self.a),
+        ]
+    }
+
+    /// An origin and two orthonormal axes spanning the plane of the triangle, with the origin at
+    /// vertex a and the first axis along the edge a → b
+    pub fn to_plane_basis(&self) -> (Point, Vector3, Vector3) {
+        let normal = self.plane().normal;
+        let u = (self.b - self.a).normalized();
+        let v = normal.cross(u);
+        (self.a, u, v)
+    }
+
+    /// The 2D coordinates of a point projected into the plane basis of the triangle
+    pub fn project_to_2d(&self, p: Point) -> (f32, f32) {
+        let (origin, u, v) = self.to_plane_basis();
+        let diff = p - origin;
+        (diff.dot(u), diff.dot(v))
+    }
+
+    /// The centroid of the triangle, the average of its vertices
+    pub fn centroid(&self) -> Point {
+        Point::from((Vector3::from(self.a) + Vector3::from(self.b) + Vector3::from(self.c)) / 3.0)
+    }
+
+    /// The area of the triangle
+    pub fn area(&self) -> f32 {
+        (self.b - self.a).cross(self.c - self.a).magnitude() * 0.5
+    }
+
+    /// A copy of this triangle with each vertex scaled about the centroid by the given factor.
+    ///
+    /// Factors less than one shrink the triangle, and factors greater than one grow it.
+    pub fn scaled(&self, factor: f32) -> Triangle {
+        let centroid = self.centroid();
+        let scale = |p: Point| centroid + (p - centroid) * factor;
+        Triangle::new(scale(self.a), scale(self.b), scale(self.c))
+    }
+
+    /// Split the triangle at the midpoints of its edges into four smaller triangles, one at each
+    /// corner followed by the one in the middle, all with the same winding as the original
+    pub fn subdivide(&self) -> [Triangle; 4] {
+        let ab = self.a + (self.b - self.a) * 0.5;
+        let bc = self.b + (self.c - self.b) * 0.5;
+        let ca = self.c + (self.a - self.c) * 0.5;
+        [
+            Triangle::new(self.a, ab, ca),
+            Triangle::new(ab, self.b, bc),
+            Triangle::new(ca, bc, self.c),
+            Triangle::new(ab, bc, ca),
+        ]
+    }
+
+    /// The center of the circle passing through all three vertices.
+    ///
+    /// Degenerate triangles have no such circle, and instead return the midpoint of the longest
+    /// edge, the center of the smallest circle enclosing the vertices.
+    pub fn circumcenter(&self) -> Point {
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+        let n = ab.cross(ac);
+
+        // the squared sine of the angle at a, scaled by the squared lengths of its edges
+        let n_squared = n.magnitude_squared();
+        if n_squared
+            <= ANGULAR_EPSILON * ANGULAR_EPSILON * ab.magnitude_squared() * ac.magnitude_squared()
+        {
+            let longest = self
+                .edges()
+                .into_iter()
+                .max_by(|e0, e1| {
+                    (e0.end - e0.start)
+                        .magnitude_squared()
+                        .total_cmp(&(e1.end - e1.start).magnitude_squared())
+                })
+                .unwrap();
+            return longest.point_at(0.5);
+        }
+
+        self.a
+            + (n.cross(ab) * ac.magnitude_squared() + ac.cross(n) * ab.magnitude_squared())
+                / (2.0 * n_squared)
+    }
+
+    /// The radius of the circle passing through all three vertices, falling back to half the
+    /// longest edge for degenerate triangles (see `circumcenter`)
+    pub fn circumradius(&self) -> f32 {
+        let center = self.circumcenter();
+        self.vertices()
+            .iter()
+            .map(|v| (*v - center).magnitude())
+            .fold(0.0, f32::max)
+    }
+
+    /// The center of the largest circle that fits inside the triangle.
+    ///
+    /// This is always well defined, except when all three vertices coincide, in which case it
+    /// is that vertex.
+    pub fn incenter(&self) -> Point {
+        // each vertex is weighted by the length of the opposite edge
+        let la = (self.c - self.b).magnitude();
+        let lb = (self.a - self.c).magnitude();
+        let lc = (self.b - self.a).magnitude();
+        let perimeter = la + lb + lc;
+        if perimeter <= LINEAR_EPSILON * scale_of(&self.vertices()) {
+            return self.a;
+        }
+
+        Point::from(
+            (Vector3::from(self.a) * la + Vector3::from(self.b) * lb + Vector3::from(self.c) * lc)
+                / perimeter,
+        )
+    }
+
+    /// Whether this triangle overlaps another triangle lying in the same plane, including
+    /// triangles that only touch along an edge or at a vertex.
+    ///
+    /// Both triangles are projected into the plane basis of this triangle, so the result is
+    /// meaningless if they aren't coplanar.
+    pub fn coplanar_overlap(&self, other: &Triangle) -> bool {
+        let a = self.vertices().map(|p| self.project_to_2d(p));
+        let b = other.vertices().map(|p| self.project_to_2d(p));
+        let [p, q, r] = self.vertices();
+        let tolerance = LINEAR_EPSILON * scale_of(&[p, q, r, other.a, other.b, other.c]);
+
+        // any pair of edges crossing
+        for i in 0..3 {
+            for j in 0..3 {
+                if segments_intersect_2d(a[i], a[(i + 1) % 3], b[j], b[(j + 1) % 3], tolerance) {
+                    return true;
+                }
+            }
+        }
+
+        // or either triangle entirely containing the other
+        point_in_triangle_2d(a[0], b) || point_in_triangle_2d(b[0], a)
+    }
+
+    /// Points spanning the overlap between this triangle and another lying in the same plane:
+    /// the vertices of each that lie inside the other, and the points where their edges cross
+    pub(crate) fn coplanar_overlap_points(&self, other: &Triangle) -> Vec<Point> {
+        let mut points: Vec<Point> = self
+            .vertices()
+            .into_iter()
+            .filter(|p| other.coplanar_point_inside(*p))
+            .collect();
+        points.extend(
+            other
+                .vertices()
+                .into_iter()
+                .filter(|p| self.coplanar_point_inside(*p)),
+        );
+
+        let a = self.vertices().map(|p| self.project_to_2d(p));
+        let b = other.vertices().map(|p| self.project_to_2d(p));
+        let edges = self.edges();
+        for i in 0..3 {
+            for j in 0..3 {
+                let (p, q) = (a[i], a[(i + 1) % 3]);
+                let (r, s) = (b[j], b[(j + 1) % 3]);
+                let d1 = orient_2d(r, s, p);
+                let d2 = orient_2d(r, s, q);
+                if d1 * d2 < 0.0 && orient_2d(p, q, r) * orient_2d(p, q, s) < 0.0 {
+                    points.push(edges[i].point_at(d1 / (d1 - d2)));
+                }
+            }
+        }
+
+        points
+    }
+
+    /// The closest point on the triangle to another point, and the feature of the triangle on
+    /// which it lies
+    pub fn closest_feature(&self, p: Point) -> (Feature, Point) {
+        let q = self.plane().closest_point(&p);
+        let coordinates = self.barycentric_coordinates(q);
+        if coordinates.x >= 0.0 && coordinates.y >= 0.0 && coordinates.z >= 0.0 {
+            return (Feature::Face, q);
+        }
+
+        // prefer the earlier edge on ties, which occur when the closest point is a shared vertex
+        let mut result = (Feature::Face, q);
+        let mut best = f32::INFINITY;
+        for (i, edge) in self.edges().iter().enumerate() {
+            let direction = edge.end - edge.start;
+            let t =
+                ((p - edge.start).dot(direction) / direction.magnitude_squared()).clamp(0.0, 1.0);
+            let closest = edge.point_at(t);
+            let distance = (p - closest).magnitude_squared();
+            if distance < best {
+                best = distance;
+                let feature = if t <= 0.0 {
+                    Feature::Vertex(i)
+                } else if t >= 1.0 {
+                    Feature::Vertex((i + 1) % 3)
+                } else {
+                    Feature::Edge(i)
+                };
+                result = (feature, closest);
+            }
+        }
+
+        result
+    }
+
     /// Barycentric coordinates of the given point
-    pub(crate) fn barycentric_coordinates(&self, p: Point) -> Vector3 {
+    pub fn barycentric_coordinates(&self, p: Point) -> Vector3 {
         let e0 = self.b - self.a;
         let e1 = self.c - self.a;
         let e2 = p - self.a;
@@ -34,9 +269,18 @@ impl Triangle {
         Vector3::new(u, v, w)
     }
 
+    /// The point with the given barycentric coordinates, the inverse of `barycentric_coordinates`
+    pub fn point_from_barycentric(&self, coords: Vector3) -> Point {
+        Point::from(
+            Vector3::from(self.a) * coords.x
+                + Vector3::from(self.b) * coords.y
+                + Vector3::from(self.c) * coords.z,
+        )
+    }
+
     /// Test if a coplanar point is inside the triangle
     pub(crate) fn coplanar_point_inside(&self, p: Point) -> bool {
-        let plane = Plane::from(self);
+        let plane = self.plane();
 
         let edge_cross = (self.b - self.a).cross(p - self.a);
         // reject if intersection is outside of edge
@@ -58,4 +302,871 @@ impl Triangle {
 
         true
     }
+
+    /// The closest points between this triangle and a line segment, as (on triangle, on segment)
+    pub(crate) fn closest_points_to_segment(&self, segment: &LineSegment) -> (Point, Point) {
+        let plane = self.plane();
+
+        // if the segment passes through the triangle, the closest points coincide
+        let direction = segment.end - segment.start;
+        let n_dot_d = plane.normal.dot(direction);
+        if !nearly_parallel(n_dot_d, direction, ANGULAR_EPSILON) {
+            let t = -plane.normal.dot(segment.start - self.a) / n_dot_d;
+            if (0.0..=1.0).contains(&t) {
+                let p = segment.point_at(t);
+                if self.coplanar_point_inside(p) {
+                    return (p, p);
+                }
+            }
+        }
+
+        // otherwise the closest points involve either an endpoint of the segment or an edge of the triangle
+        let ends = [
+            (self.closest_point(&segment.start), segment.start),
+            (self.closest_point(&segment.end), segment.end),
+        ];
+        let edges = self.edges().map(|edge| {
+            let (s, t) = edge.closest_parameters(segment);
+            (edge.point_at(s), segment.point_at(t))
+        });
+        closest_pair(ends.into_iter().chain(edges))
+    }
+
+    /// The point on this triangle closest to a line segment
+    pub fn closest_point_on_triangle_to_segment(&self, segment: &LineSegment) -> Point {
+        self.closest_points_to_segment(segment).0
+    }
+
+    /// Where a ray hits this triangle, as the distance along the ray and the barycentric
+    /// coordinates of the hit, which sum to 1
+    pub fn raycast_barycentric(&self, ray: &Ray) -> Option<(f32, Vector3)> {
+        self.raycast(ray)
+            .map(|hit| (hit.t, self.barycentric_coordinates(hit.point)))
+    }
+
+    /// Where a ray hits this triangle, optionally rejecting hits on the back face, where the ray
+    /// travels in the same direction as the normal
+    pub fn raycast_sided(&self, ray: &Ray, cull_back: bool) -> Option<RayHit> {
+        self.raycast_with(ray, &RaycastOptions::default().with_cull_back(cull_back))
+    }
+
+    /// The closest points between a ray and this triangle, as (on ray, on triangle)
+    pub fn closest_point_to_ray(&self, ray: &Ray) -> (Point, Point) {
+        // if the ray hits the triangle, the closest points coincide
+        if let Some(hit) = self.raycast(ray) {
+            return (hit.point, hit.point);
+        }
+
+        // otherwise the closest points involve either the origin of the ray or an edge of the triangle
+        let origin = (ray.origin, self.closest_point(&ray.origin));
+        let edges = self.edges().map(|edge| {
+            let (t, s) = closest_parameters(
+                ray.origin,
+                ray.direction,
+                0.0..=f32::INFINITY,
+                edge.start,
+                edge.end - edge.start,
+                0.0..=1.0,
+            );
+            (ray.origin + ray.direction * t, edge.point_at(s))
+        });
+        closest_pair(std::iter::once(origin).chain(edges))
+    }
+}
+
+/// The pair of points nearest to one another, from a non-empty set of candidates
+fn closest_pair(candidates: impl Iterator<Item = (Point, Point)>) -> (Point, Point) {
+    candidates
+        .min_by(|(p0, q0), (p1, q1)| {
+            (*p0 - *q0)
+                .magnitude_squared()
+                .total_cmp(&(*p1 - *q1).magnitude_squared())
+        })
+        .unwrap()
+}
+
+/// Twice the signed area of the 2D triangle pqr, positive when counter-clockwise
+fn orient_2d(p: (f32, f32), q: (f32, f32), r: (f32, f32)) -> f32 {
+    (q.0 - p.0) * (r.1 - p.1) - (q.1 - p.1) * (r.0 - p.0)
+}
+
+/// Whether the 2D point r, known to be collinear with pq, lies within their bounds, give or take
+/// the tolerance
+fn on_segment_2d(p: (f32, f32), q: (f32, f32), r: (f32, f32), tolerance: f32) -> bool {
+    r.0 >= p.0.min(q.0) - tolerance
+        && r.0 <= p.0.max(q.0) + tolerance
+        && r.1 >= p.1.min(q.1) - tolerance
+        && r.1 <= p.1.max(q.1) + tolerance
+}
+
+/// Whether the 2D segments pq and rs intersect, including touching at their end points, where
+/// an end point touches the other segment if it lies within the tolerance of it
+fn segments_intersect_2d(
+    p: (f32, f32),
+    q: (f32, f32),
+    r: (f32, f32),
+    s: (f32, f32),
+    tolerance: f32,
+) -> bool {
+    let d1 = orient_2d(r, s, p);
+    let d2 = orient_2d(r, s, q);
+    let d3 = orient_2d(p, q, r);
+    let d4 = orient_2d(p, q, s);
+
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return true;
+    }
+
+    // the orientation is twice the area, so the distance from the line is that over the length
+    let near_rs = tolerance * (s.0 - r.0).hypot(s.1 - r.1);
+    let near_pq = tolerance * (q.0 - p.0).hypot(q.1 - p.1);
+    (d1.abs() <= near_rs && on_segment_2d(r, s, p, tolerance))
+        || (d2.abs() <= near_rs && on_segment_2d(r, s, q, tolerance))
+        || (d3.abs() <= near_pq && on_segment_2d(p, q, r, tolerance))
+        || (d4.abs() <= near_pq && on_segment_2d(p, q, s, tolerance))
+}
+
+/// Whether the 2D point p lies inside or on the boundary of a 2D triangle of either winding
+fn point_in_triangle_2d(p: (f32, f32), triangle: [(f32, f32); 3]) -> bool {
+    let d0 = orient_2d(triangle[0], triangle[1], p);
+    let d1 = orient_2d(triangle[1], triangle[2], p);
+    let d2 = orient_2d(triangle[2], triangle[0], p);
+    (d0 >= 0.0 && d1 >= 0.0 && d2 >= 0.0) || (d0 <= 0.0 && d1 <= 0.0 && d2 <= 0.0)
+}
+
+impl Default for Triangle {
+    /// A triangle in the XZ plane, facing +Y
+    fn default() -> Self {
+        Self::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        )
+    }
+}
+
+impl std::ops::Add<Vector3> for Triangle {
+    type Output = Triangle;
+
+    /// Translate by the given vector
+    fn add(self, v: Vector3) -> Triangle {
+        Self::new(self.a + v, self.b + v, self.c + v)
+    }
+}
+
+impl std::ops::Sub<Vector3> for Triangle {
+    type Output = Triangle;
+
+    /// Translate by the opposite of the given vector
+    fn sub(self, v: Vector3) -> Triangle {
+        Self::new(self.a - v, self.b - v, self.c - v)
+    }
+}
+
+/// The signed distance from a point to the surface of a closed triangle mesh, which is negative
+/// inside the mesh.
+///
+/// The sign is determined by the angle-weighted pseudonormal at the closest feature (face, edge
+/// or vertex) of the nearest triangle. This requires the triangles to be consistently wound with
+/// their normals facing outwards, and adjacent triangles to share exactly equal vertices.
+/// Returns infinity for an empty mesh.
+pub fn mesh_signed_distance(tris: &[Triangle], p: Point) -> f32 {
+    let nearest = tris
+        .iter()
+        .map(|triangle| (triangle, triangle.closest_point(&p)))
+        .min_by(|(_, a), (_, b)| {
+            (p - *a)
+                .magnitude_squared()
+                .total_cmp(&(p - *b).magnitude_squared())
+        });
+
+    let (triangle, q) = match nearest {
+        Some(nearest) => nearest,
+        None => return f32::INFINITY,
+    };
+
+    // the vertices spanning the feature on which the closest point lies
+    let barycentric = triangle.barycentric_coordinates(q);
+    let feature: Vec<Point> = triangle
+        .vertices()
+        .into_iter()
+        .zip([barycentric.x, barycentric.y, barycentric.z])
+        .filter(|(_, weight)| *weight > ANGULAR_EPSILON)
+        .map(|(vertex, _)| vertex)
+        .collect();
+
+    let pseudonormal = match feature.as_slice() {
+        [vertex] => tris
+            .iter()
+            .filter_map(|triangle| {
+                let vertices = triangle.vertices();
+                let i = vertices.iter().position(|v| v == vertex)?;
+                let e0 = (vertices[(i + 1) % 3] - *vertex).normalized();
+                let e1 = (vertices[(i + 2) % 3] - *vertex).normalized();
+                let angle = e0.dot(e1).clamp(-1.0, 1.0).acos();
+                Some(triangle.plane().normal * angle)
+            })
+            .fold(Vector3::zero(), |sum, n| sum + n),
+        [start, end] => tris
+            .iter()
+            .filter(|triangle| {
+                let vertices = triangle.vertices();
+                vertices.contains(start) && vertices.contains(end)
+            })
+            .map(|triangle| triangle.plane().normal)
+            .fold(Vector3::zero(), |sum, n| sum + n),
+        _ => triangle.plane().normal,
+    };
+
+    let distance = (p - q).magnitude();
+    if (p - q).dot(pseudonormal) < 0.0 {
+        -distance
+    } else {
+        distance
+    }
+}
+
+/// The indices of the `k` triangles closest to a point, along with their distances, sorted
+/// nearest first. Triangles at equal distances are ordered by index.
+pub fn k_nearest_triangles(tris: &[Triangle], p: Point, k: usize) -> Vec<(usize, f32)> {
+    /// A candidate ordered by distance and then index, so that the heap holds the farthest on top
+    struct Candidate(f32, usize);
+
+    impl PartialEq for Candidate {
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == std::cmp::Ordering::Equal
+        }
+    }
+
+    impl Eq for Candidate {}
+
+    impl PartialOrd for Candidate {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Candidate {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.total_cmp(&other.0).then(self.1.cmp(&other.1))
+        }
+    }
+
+    if k == 0 {
+        return vec![];
+    }
+
+    let mut heap = std::collections::BinaryHeap::with_capacity(k.min(tris.len()));
+
+    for (index, triangle) in tris.iter().enumerate() {
+        let candidate = Candidate(triangle.distance(&p), index);
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if heap.peek().is_some_and(|farthest| candidate < *farthest) {
+            heap.pop();
+            heap.push(candidate);
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Candidate(distance, index)| (index, distance))
+        .collect()
+}
+
+/// The fraction along a cast from `start` to `end` at which a sphere of the given radius first
+/// touches a triangle, treating the cast as a swept sphere (or equivalently, a ray with
+/// thickness).
+///
+/// Returns zero if the sphere already touches the triangle at the start of the cast, and `None`
+/// if it never touches the triangle before reaching the end.
+pub fn capsule_cast_triangle(start: Point, end: Point, radius: f32, tri: &Triangle) -> Option<f32> {
+    if tri.distance(&start) <= radius {
+        return Some(0.0);
+    }
+
+    // the sphere touching the face of the triangle from whichever side it starts on
+    let plane = tri.plane();
+    let d0 = plane.signed_distance(&start);
+    let d1 = plane.signed_distance(&end);
+    let side = d0.signum();
+    let mut face = None;
+    if (d0 - d1) * side > 0.0 {
+        let t = (d0 - side * radius) / (d0 - d1);
+        let center = start + (end - start) * t;
+        if tri.coplanar_point_inside(center - plane.normal * (side * radius)) {
+            face = Some(t);
+        }
+    }
+
+    // the sphere touching an edge or vertex is the path of its center hitting a capsule
+    let ray = Ray::new(start, end - start);
+    let edges = tri.edges().map(|edge| {
+        Capsule::new(edge.start, edge.end, radius)
+            .raycast(&ray)
+            .map(|hit| hit.t)
+    });
+
+    face.into_iter()
+        .chain(edges.into_iter().flatten())
+        .filter(|t| (0.0..=1.0).contains(t))
+        .min_by(|a, b| a.total_cmp(b))
+}
+
+impl From<[Point; 3]> for Triangle {
+    fn from([a, b, c]: [Point; 3]) -> Self {
+        Self::new(a, b, c)
+    }
+}
+
+impl From<(Point, Point, Point)> for Triangle {
+    fn from((a, b, c): (Point, Point, Point)) -> Self {
+        Self::new(a, b, c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Intersection;
+
+    #[test]
+    fn test_vertices_and_edges() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        let vertices = triangle.vertices();
+        assert_eq!(vertices, [triangle.a, triangle.b, triangle.c]);
+
+        let edges = triangle.edges();
+        for i in 0..3 {
+            assert_eq!(edges[i].start, vertices[i]);
+            assert_eq!(edges[i].end, vertices[(i + 1) % 3]);
+        }
+    }
+
+    #[test]
+    fn test_project_to_2d() {
+        let triangle = Triangle::new(
+            Point::new(1.0, 2.0, 3.0),
+            Point::new(4.0, 2.0, -1.0),
+            Point::new(0.0, 5.0, 1.0),
+        );
+
+        let (origin, u, v) = triangle.to_plane_basis();
+        assert_eq!(origin, triangle.a);
+        assert!((u.magnitude() - 1.0).abs() < 1e-6);
+        assert!((v.magnitude() - 1.0).abs() < 1e-6);
+        assert!(u.dot(v).abs() < 1e-6);
+
+        let (ax, ay) = triangle.project_to_2d(triangle.a);
+        let (bx, by) = triangle.project_to_2d(triangle.b);
+        let (cx, cy) = triangle.project_to_2d(triangle.c);
+        assert_eq!((ax, ay), (0.0, 0.0));
+        assert!((bx - 5.0).abs() < 1e-6);
+        assert!(by.abs() < 1e-6);
+
+        // distances between the vertices are preserved
+        let distance =
+            |x0: f32, y0: f32, x1: f32, y1: f32| ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+        assert!((distance(ax, ay, bx, by) - (triangle.b - triangle.a).magnitude()).abs() < 1e-5);
+        assert!((distance(bx, by, cx, cy) - (triangle.c - triangle.b).magnitude()).abs() < 1e-5);
+        assert!((distance(cx, cy, ax, ay) - (triangle.a - triangle.c).magnitude()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_raycast_barycentric() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(2.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 2.0),
+        );
+
+        // through the centroid
+        let ray = Ray::new(
+            Point::new(1.0 / 3.0, 2.0, 0.0),
+            Vector3::new(0.0, -1.0, 0.0),
+        );
+        let (t, coordinates) = triangle.raycast_barycentric(&ray).unwrap();
+        assert!((t - 2.0).abs() < 1e-6);
+        assert!((coordinates - Vector3::from_scalar(1.0 / 3.0)).magnitude() < 1e-6);
+
+        // through vertex b
+        let ray = Ray::new(Point::new(2.0, 2.0, -1.0), Vector3::new(0.0, -1.0, 0.0));
+        let (_, coordinates) = triangle.raycast_barycentric(&ray).unwrap();
+        assert!((coordinates - Vector3::new(0.0, 1.0, 0.0)).magnitude() < 1e-6);
+
+        // beside the triangle
+        let ray = Ray::new(Point::new(3.0, 2.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(triangle.raycast_barycentric(&ray), None);
+    }
+
+    #[test]
+    fn test_closest_point_to_ray() {
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, 1.0),
+        );
+
+        // hitting the face
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            triangle.closest_point_to_ray(&ray),
+            (Point::new(0.0, 0.0, 0.0), Point::new(0.0, 0.0, 0.0))
+        );
+
+        // passing beside the a-b edge
+        let ray = Ray::new(Point::new(0.0, 2.0, -2.0), Vector3::new(0.0, -1.0, 0.0));
+        assert_eq!(
+            triangle.closest_point_to_ray(&ray),
+            (Point::new(0.0, 0.0, -2.0), Point::new(0.0, 0.0, -1.0))
+        );
+
+        // pointing away from the triangle
+        let ray = Ray::new(Point::new(0.0, 2.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(
+            triangle.closest_point_to_ray(&ray),
+            (Point::new(0.0, 2.0, 0.0), Point::new(0.0, 0.0, 0.0))
+        );
+
+        // skew to the a-b edge, passing below the triangle
+        let ray = Ray::new(Point::new(0.0, -1.0, -3.0), Vector3::new(0.0, 0.0, 1.0));
+        let (p, q) = triangle.closest_point_to_ray(&ray);
+        assert!(((p - q).magnitude() - 1.0).abs() < 1e-6);
+        assert_eq!(q.y, 0.0);
+    }
+
+    #[test]
+    fn test_default() {
+        let triangle = Triangle::default();
+        assert_eq!(Plane::from(&triangle).normal, Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_mesh_signed_distance() {
+        let a = Point::new(0.0, 0.0, 0.0);
+        let b = Point::new(1.0, 0.0, 0.0);
+        let c = Point::new(0.0, 1.0, 0.0);
+        let d = Point::new(0.0, 0.0, 1.0);
+        let tetrahedron = [
+            Triangle::new(a, b, c),
+            Triangle::new(a, d, b),
+            Triangle::new(a, c, d),
+            Triangle::new(b, d, c),
+        ];
+
+        // every face points away from the interior
+        let centroid = Point::new(0.25, 0.25, 0.25);
+        for triangle in &tetrahedron {
+            assert!(Plane::from(triangle).normal.dot(triangle.a - centroid) > 0.0);
+        }
+
+        // interior points, closest to a face
+        assert!((mesh_signed_distance(&tetrahedron, Point::new(0.1, 0.1, 0.1)) + 0.1).abs() < 1e-6);
+        assert!((mesh_signed_distance(&tetrahedron, Point::new(0.2, 0.2, 0.2)) + 0.2).abs() < 1e-6);
+
+        // exterior point, closest to a face
+        let distance = mesh_signed_distance(&tetrahedron, Point::new(1.0, 1.0, 1.0));
+        assert!((distance - 2.0 / 3.0f32.sqrt()).abs() < 1e-5);
+
+        // exterior point, closest to an edge
+        let distance = mesh_signed_distance(&tetrahedron, Point::new(-1.0, -1.0, 0.5));
+        assert!((distance - 2.0f32.sqrt()).abs() < 1e-5);
+
+        // exterior point, closest to a vertex
+        let distance = mesh_signed_distance(&tetrahedron, Point::new(-1.0, -1.0, -1.0));
+        assert!((distance - 3.0f32.sqrt()).abs() < 1e-5);
+
+        assert_eq!(mesh_signed_distance(&[], Point::zero()), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_point_from_barycentric() {
+        let triangle = Triangle::new(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(3.0, 1.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+        );
+
+        assert_eq!(
+            triangle.point_from_barycentric(Vector3::new(0.0, 1.0, 0.0)),
+            triangle.b
+        );
+
+        // round trip points inside and outside the triangle, all within its plane
+        for (v, w) in [(0.2, 0.3), (0.5, 0.5), (-0.5, 0.25), (1.5, 2.0)] {
+            let p = triangle.a + (triangle.b - triangle.a) * v + (triangle.c - triangle.a) * w;
+            let coords = triangle.barycentric_coordinates(p);
+            assert!((triangle.point_from_barycentric(coords) - p).magnitude() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_closest_points_between_segment_and_triangle() {
+        use crate::Distance;
+
+        let triangle = Triangle::default();
+
+        for segment in [
+            LineSegment::new(Point::new(2.0, 1.0, -2.0), Point::new(2.0, 3.0, 2.0)),
+            LineSegment::new(Point::new(-0.5, 1.0, 3.0), Point::new(0.5, 0.5, 3.0)),
+            LineSegment::new(Point::new(0.2, 2.0, 0.0), Point::new(0.0, 0.5, -0.2)),
+        ] {
+            let p = triangle.closest_point_on_triangle_to_segment(&segment);
+            let q = segment.closest_point_on_segment_to_triangle(&triangle);
+
+            // each point is the closest to the other
+            assert!((triangle.closest_point(&q) - p).magnitude() < 1e-5);
+            assert!((segment.closest_point(&p) - q).magnitude() < 1e-5);
+
+            // and no point along the segment is any closer to the triangle
+            let distance = (q - p).magnitude();
+            for i in 0..=100 {
+                let sample = segment.point_at(i as f32 / 100.0);
+                assert!(triangle.distance(&sample) >= distance - 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_capsule_cast_triangle() {
+        let triangle = Triangle::default();
+
+        // a thin ray passes just outside the edge along z = -1
+        let start = Point::new(0.0, 5.0, -1.5);
+        let end = Point::new(0.0, -5.0, -1.5);
+        assert_eq!(capsule_cast_triangle(start, end, 0.0, &triangle), None);
+        assert_eq!(capsule_cast_triangle(start, end, 0.4, &triangle), None);
+
+        // but a thick one clips the edge
+        let t = capsule_cast_triangle(start, end, 1.0, &triangle).unwrap();
+        assert!((t - (5.0 - 0.75f32.sqrt()) / 10.0).abs() < 1e-5);
+
+        // straight onto the face, from either side
+        let t = capsule_cast_triangle(
+            Point::new(0.0, 5.0, 0.0),
+            Point::new(0.0, -5.0, 0.0),
+            1.0,
+            &triangle,
+        );
+        assert_eq!(t, Some(0.4));
+        let t = capsule_cast_triangle(
+            Point::new(0.0, -5.0, 0.0),
+            Point::new(0.0, 5.0, 0.0),
+            1.0,
+            &triangle,
+        );
+        assert_eq!(t, Some(0.4));
+
+        // stopping short of the triangle
+        let t = capsule_cast_triangle(
+            Point::new(0.0, 5.0, 0.0),
+            Point::new(0.0, 2.0, 0.0),
+            1.0,
+            &triangle,
+        );
+        assert_eq!(t, None);
+
+        // already touching
+        let t = capsule_cast_triangle(
+            Point::new(0.0, 0.5, 0.0),
+            Point::new(0.0, 5.0, 0.0),
+            1.0,
+            &triangle,
+        );
+        assert_eq!(t, Some(0.0));
+    }
+
+    #[test]
+    fn test_raycast_sided() {
+        let triangle = Triangle::default();
+
+        // hitting the front face
+        let ray = Ray::new(Point::new(0.0, 1.0, 0.0), Vector3::new(0.0, -1.0, 0.0));
+        assert!(triangle.raycast_sided(&ray, true).is_some());
+        assert!(triangle.raycast_sided(&ray, false).is_some());
+
+        // hitting the back face
+        let ray = Ray::new(Point::new(0.0, -1.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert_eq!(triangle.raycast_sided(&ray, true), None);
+        assert_eq!(triangle.raycast_sided(&ray, false), triangle.raycast(&ray));
+        assert!(triangle.raycast(&ray).is_some());
+    }
+
+    #[test]
+    fn test_circles() {
+        // equilateral, so the centers coincide with the centroid
+        let h = 3.0f32.sqrt();
+        let triangle = Triangle::new(
+            Point::new(-1.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(0.0, h, 0.0),
+        );
+        let centroid = Point::new(0.0, h / 3.0, 0.0);
+        assert!((triangle.circumcenter() - centroid).magnitude() < 1e-6);
+        assert!((triangle.incenter() - centroid).magnitude() < 1e-6);
+        assert!((triangle.circumradius() - 2.0 / h).abs() < 1e-6);
+
+        // a right triangle has its circumcenter at the middle of the hypotenuse
+        let triangle = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 0.0),
+            Point::new(0.0, 0.0, 4.0),
+        );
+        assert!((triangle.circumcenter() - Point::new(1.5, 0.0, 2.0)).magnitude() < 1e-6);
+        assert!((triangle.circumradius() - 2.5).abs() < 1e-6);
+        // with an inradius of (3 + 4 - 5) / 2 = 1
+        assert!((triangle.incenter() - Point::new(1.0, 0.0, 1.0)).magnitude() < 1e-6);
+
+        // degenerate triangles fall back to the longest edge
+        let triangle = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(4.0, 0.0, 0.0),
+        );
+        assert_eq!(triangle.circumcenter(), Point::new(2.0, 0.0, 0.0));
+        assert_eq!(triangle.circumradius(), 2.0);
+
+        let p = Point::new(1.0, 2.0, 3.0);
+        assert_eq!(Triangle::new(p, p, p).incenter(), p);
+        // small triangles are not degenerate
+        let triangle = Triangle::new(
+            Point::new(-0.005, 0.0, 0.0),
+            Point::new(0.005, 0.0, 0.0),
+            Point::new(0.0, 0.005 * h, 0.0),
+        );
+        let centroid = Point::new(0.0, 0.005 * h / 3.0, 0.0);
+        assert!((triangle.circumcenter() - centroid).magnitude() < 1e-7);
+        assert!((triangle.circumradius() - 0.01 / h).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_coplanar_overlap() {
+        let triangle = Triangle::default();
+
+        // overlapping
+        let other = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(1.0, 0.0, 2.0),
+        );
+        assert!(triangle.coplanar_overlap(&other));
+        assert!(other.coplanar_overlap(&triangle));
+
+        // touching along the edge z = -1, wound in the opposite direction
+        let other = Triangle::new(
+            Point::new(-1.0, 0.0, -1.0),
+            Point::new(1.0, 0.0, -1.0),
+            Point::new(0.0, 0.0, -3.0),
+        );
+        assert!(triangle.coplanar_overlap(&other));
+        assert!(other.coplanar_overlap(&triangle));
+
+        // entirely contained
+        let other = Triangle::new(
+            Point::new(-0.1, 0.0, -0.1),
+            Point::new(0.1, 0.0, -0.1),
+            Point::new(0.0, 0.0, 0.1),
+        );
+        assert!(triangle.coplanar_overlap(&other));
+        assert!(other.coplanar_overlap(&triangle));
+
+        // disjoint
+        let other = Triangle::new(
+            Point::new(2.0, 0.0, 0.0),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(3.0, 0.0, 2.0),
+        );
+        assert!(!triangle.coplanar_overlap(&other));
+        assert!(!other.coplanar_overlap(&triangle));
+    }
+
+    #[test]
+    fn test_scaled() {
+        let triangle = Triangle::new(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(3.0, 1.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+        );
+
+        assert_eq!(triangle.scaled(1.0), triangle);
+
+        for factor in [0.5, 2.0, 3.0] {
+            let scaled = triangle.scaled(factor);
+            assert!((scaled.centroid() - triangle.centroid()).magnitude() < 1e-5);
+            assert!((scaled.area() - triangle.area() * factor * factor).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_from_vertices() {
+        let a = Point::new(1.0, 0.0, 0.0);
+        let b = Point::new(3.0, 1.0, 0.0);
+        let c = Point::new(0.0, 2.0, 1.0);
+
+        let triangle = Triangle::from([a, b, c]);
+        assert_eq!(triangle.a, a);
+        assert_eq!(triangle.b, b);
+        assert_eq!(triangle.c, c);
+
+        assert_eq!(Triangle::from((a, b, c)), triangle);
+        assert_eq!(Triangle::from(triangle.vertices()), triangle);
+    }
+
+    #[test]
+    fn test_translation() {
+        let triangle = Triangle::new(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(3.0, 1.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+        );
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        let moved = triangle + v;
+        assert_eq!(moved.a, triangle.a + v);
+        assert_eq!(moved.b, triangle.b + v);
+        assert_eq!(moved.c, triangle.c + v);
+        assert!((Plane::from(&moved).normal - Plane::from(&triangle).normal).magnitude() < 1e-6);
+
+        assert_eq!(moved - v, triangle);
+    }
+
+    #[test]
+    fn test_plane() {
+        let triangle = Triangle::new(
+            Point::new(1.0, 0.0, 0.0),
+            Point::new(3.0, 1.0, 0.0),
+            Point::new(0.0, 2.0, 1.0),
+        );
+        assert_eq!(triangle.plane(), Plane::from(&triangle));
+    }
+
+    #[test]
+    fn test_subdivide() {
+        let triangle = Triangle::new(
+            Point::new(0.0, 0.0, 0.0),
+            Point::new(4.0, 0.0, 0.0),
+            Point::new(0.0, 2.0, 2.0),
+        );
+        let parts = triangle.subdivide();
+
+        let area: f32 = parts.iter().map(|t| t.area()).sum();
+        assert!((area - triangle.area()).abs() < 1e-5);
+        for part in &parts {
+            assert!((part.area() - triangle.area() / 4.0).abs() < 1e-5);
+            assert!((part.plane().normal - triangle.plane().normal).magnitude() < 1e-5);
+        }
+
+        // the middle triangle is made from the midpoints, each shared with two corner triangles
+        let middle = parts[3];
+        assert_eq!(
+            middle.vertices(),
+            [
+                Point::new(2.0, 0.0, 0.0),
+                Point::new(2.0, 1.0, 1.0),
+                Point::new(0.0, 1.0, 1.0),
+            ]
+        );
+        for midpoint in middle.vertices() {
+            let sharing = parts[..3]
+                .iter()
+                .filter(|t| t.vertices().contains(&midpoint))
+                .count();
+            assert_eq!(sharing, 2);
+        }
+    }
+
+    #[test]
+    fn test_closest_feature() {
+        let triangle = Triangle::default();
+
+        // above the face
+        assert_eq!(
+            triangle.closest_feature(Point::new(0.0, 2.0, 0.0)),
+            (Feature::Face, Point::new(0.0, 0.0, 0.0))
+        );
+
+        // above and beyond the a → b edge
+        assert_eq!(
+            triangle.closest_feature(Point::new(0.5, 1.0, -2.0)),
+            (Feature::Edge(0), Point::new(0.5, 0.0, -1.0))
+        );
+
+        // above and beyond vertex c, and beyond vertex a
+        assert_eq!(
+            triangle.closest_feature(Point::new(0.0, 1.0, 3.0)),
+            (Feature::Vertex(2), triangle.c)
+        );
+        assert_eq!(
+            triangle.closest_feature(Point::new(-3.0, 1.0, -2.0)),
+            (Feature::Vertex(0), triangle.a)
+        );
+    }
+
+    #[test]
+    fn test_k_nearest_triangles() {
+        // a grid of triangles in the XZ plane
+        let mut tris = vec![];
+        for x in 0..6 {
+            for z in 0..6 {
+                let corner = Point::new(x as f32 * 2.0, 0.0, z as f32 * 2.0);
+                tris.push(Triangle::new(
+                    corner,
+                    corner + Vector3::new(1.0, 0.0, 0.0),
+                    corner + Vector3::new(0.0, 0.0, 1.0),
+                ));
+            }
+        }
+
+        let p = Point::new(4.3, 1.5, 6.8);
+        let mut expected: Vec<(usize, f32)> = tris
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (i, t.distance(&p)))
+            .collect();
+        expected.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+
+        for k in [0, 1, 5, 36, 50] {
+            let nearest = k_nearest_triangles(&tris, p, k);
+            assert_eq!(nearest, expected[..k.min(tris.len())]);
+        }
+    }
+
+    #[test]
+    fn test_coplanar_overlap_rotated() {
+        // a vertex resting on an edge, in planes rotated about an arbitrary axis
+        let triangle = Triangle::default();
+        let other = Triangle::new(
+            Point::new(0.3, 0.0, -1.0),
+            Point::new(0.5, 0.0, -3.0),
+            Point::new(-0.5, 0.0, -3.0),
+        );
+        let axis = Vector3::new(1.0, 2.0, 3.0).normalized();
+        let offset = Vector3::new(0.7, -1.3, 2.1);
+
+        for i in 0..50 {
+            let (sin, cos) = (i as f32 * 0.37).sin_cos();
+            // Rodrigues' rotation formula
+            let rotate = |p: Point| {
+                let v = Vector3::from(p);
+                let rotated = v * cos + axis.cross(v) * sin + axis * axis.dot(v) * (1.0 - cos);
+                Point::from(rotated + offset)
+            };
+            let a = Triangle::new(rotate(triangle.a), rotate(triangle.b), rotate(triangle.c));
+            let b = Triangle::new(rotate(other.a), rotate(other.b), rotate(other.c));
+
+            assert!(a.coplanar_overlap(&b), "rotation {}", i);
+            assert!(b.coplanar_overlap(&a), "rotation {}", i);
+            assert!(a.intersects(&b), "rotation {}", i);
+            assert!(b.intersects(&a), "rotation {}", i);
+
+            // while a vertex just clear of the edge is still apart
+            let clear = b + (rotate(Point::new(0.0, 0.0, -0.01)) - rotate(Point::zero()));
+            assert!(!a.coplanar_overlap(&clear), "rotation {}", i);
+        }
+    }
 }